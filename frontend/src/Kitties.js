@@ -1,45 +1,113 @@
 import React, { useEffect, useState } from 'react'
-import { Form, Grid } from 'semantic-ui-react'
+import { Button, Form, Grid, Message } from 'semantic-ui-react'
+import { u8aConcat } from '@polkadot/util'
+import { blake2AsHex, decodeAddress, randomAsHex } from '@polkadot/util-crypto'
 
 import { useSubstrateState } from './substrate-lib'
 import { TxButton } from './substrate-lib/components'
 
 import KittyCards from './KittyCards'
 
-const parseKitty = ({ dna, price, gender, owner }) => ({
+// Page size used when listing the kitties for sale, capped by the node at 100.
+// The listings are shown one page at a time.
+const SALE_PAGE_SIZE = 100
+
+// Kitties are minted in two phases: the hash of the account and a secret is
+// committed first, and the secret is revealed a few blocks later. The secret
+// is kept in the browser in between: the next one is drawn ahead as a draft,
+// and is stored under its commitment once that commitment is on chain. Stored
+// secrets are never overwritten, so an in-flight commitment keeps its secret.
+const draftKey = address => `kitties:mint-draft:${address}`
+const secretPrefix = address => `kitties:mint-secret:${address}:`
+const secretKey = (address, commitment) =>
+  `${secretPrefix(address)}${commitment}`
+
+const mintCommitment = (address, secret) =>
+  blake2AsHex(u8aConcat(decodeAddress(address), secret), 256)
+
+const parseKitty = ({ dna, owner, price, gender }) => ({
+  id: dna,
   dna,
-  price: price.toJSON(),
-  gender: gender.toJSON(),
-  owner: owner.toJSON(),
+  owner,
+  price,
+  gender,
 })
 
-function toHexString(byteArray) {
-  var s = '0x'
-  byteArray.forEach(function (byte) {
-    s += ('0' + (byte & 0xff).toString(16)).slice(-2)
-  })
-  return s
-}
-
 export default function Kitties(props) {
-  const { api, keyring } = useSubstrateState()
+  const { api, currentAccount } = useSubstrateState()
   const [kitties, setKitties] = useState([])
   const [status, setStatus] = useState('')
+  const [secret, setSecret] = useState(null)
+  const [committed, setCommitted] = useState(false)
+  const [salePage, setSalePage] = useState(0)
+  const [hasNextPage, setHasNextPage] = useState(false)
 
   const subscribeCount = () => {
     let unsub = null
 
     const asyncFetch = async () => {
-      unsub = await api.query.kitties.allKittiesCount(async count => {
-        // Fetch all kitty keys
-        const entries = await api.query.kitties.kitties.entries()
-        const kittiesMap = entries.map(entry => {
-          return {
-            id: toHexString(entry[0].slice(-32)),
-            ...parseKitty(entry[1].unwrap()),
+      unsub = await api.query.kitties.allKittiesCount(async () => {
+        // Let the node collect the kitties through the kitties RPC, rather than
+        // walking every storage entry from here
+        const forSale = (
+          await api.rpc.kitties.kittiesForSale(salePage, SALE_PAGE_SIZE)
+        ).toJSON()
+        setHasNextPage(forSale.length === SALE_PAGE_SIZE)
+        const owned = currentAccount
+          ? (await api.rpc.kitties.kittiesOf(currentAccount.address)).toJSON()
+          : []
+        const kittiesMap = new Map()
+        ;[...owned, ...forSale].forEach(kitty =>
+          kittiesMap.set(kitty.dna, parseKitty(kitty))
+        )
+        setKitties([...kittiesMap.values()])
+      })
+    }
+
+    asyncFetch()
+
+    return () => {
+      unsub && unsub()
+    }
+  }
+
+  useEffect(subscribeCount, [api, currentAccount, salePage])
+
+  const subscribeCommitment = () => {
+    let unsub = null
+    setSecret(null)
+    setCommitted(false)
+    if (!currentAccount) {
+      return
+    }
+    const { address } = currentAccount
+
+    const asyncFetch = async () => {
+      unsub = await api.query.kitties.mintCommitments(address, pending => {
+        if (pending.isNone) {
+          // The secrets of past commitments were revealed or expired, and a
+          // secret is only ever revealed once
+          Object.keys(localStorage)
+            .filter(key => key.startsWith(secretPrefix(address)))
+            .forEach(key => localStorage.removeItem(key))
+          if (!localStorage.getItem(draftKey(address))) {
+            localStorage.setItem(draftKey(address), randomAsHex(32))
           }
-        })
-        setKitties(kittiesMap)
+          setSecret(localStorage.getItem(draftKey(address)))
+          setCommitted(false)
+          return
+        }
+
+        // The draft becomes the secret of the pending commitment if it was
+        // committed from this browser
+        const commitment = pending.unwrap().commitment.toHex()
+        const draft = localStorage.getItem(draftKey(address))
+        if (draft && mintCommitment(address, draft) === commitment) {
+          localStorage.setItem(secretKey(address, commitment), draft)
+          localStorage.removeItem(draftKey(address))
+        }
+        setSecret(localStorage.getItem(secretKey(address, commitment)))
+        setCommitted(true)
       })
     }
 
@@ -50,27 +118,76 @@ export default function Kitties(props) {
     }
   }
 
-  useEffect(subscribeCount, [api, keyring, kitties])
+  useEffect(subscribeCommitment, [api, currentAccount])
 
   return (
     <Grid.Column width={16}>
       <h1>Kitties</h1>
       <KittyCards kitties={kitties} setStatus={setStatus} />
+      <div style={{ textAlign: 'center', margin: '1em 0' }}>
+        <Button
+          basic
+          disabled={salePage === 0}
+          onClick={() => setSalePage(salePage - 1)}
+        >
+          Previous
+        </Button>
+        <span style={{ margin: '0 1em' }}>Listings page {salePage + 1}</span>
+        <Button
+          basic
+          disabled={!hasNextPage}
+          onClick={() => setSalePage(salePage + 1)}
+        >
+          Next
+        </Button>
+      </div>
       <Form style={{ margin: '1em 0' }}>
         <Form.Field style={{ textAlign: 'center' }}>
-          <TxButton
-            label="Create Kitty"
-            type="SIGNED-TX"
-            setStatus={setStatus}
-            attrs={{
-              palletRpc: 'kitties',
-              callable: 'createKitty',
-              inputParams: [],
-              paramFields: [],
-            }}
-          />
+          {committed ? (
+            <TxButton
+              label="Reveal Kitty"
+              type="SIGNED-TX"
+              disabled={!secret}
+              setStatus={setStatus}
+              attrs={{
+                palletRpc: 'kitties',
+                callable: 'revealMint',
+                inputParams: [secret],
+                paramFields: [true],
+              }}
+            />
+          ) : (
+            <TxButton
+              label="Create Kitty"
+              type="SIGNED-TX"
+              disabled={!secret}
+              setStatus={setStatus}
+              attrs={{
+                palletRpc: 'kitties',
+                callable: 'commitMint',
+                inputParams: [
+                  secret && mintCommitment(currentAccount.address, secret),
+                ],
+                paramFields: [true],
+              }}
+            />
+          )}
         </Form.Field>
       </Form>
+      {committed && !secret ? (
+        <Message warning>
+          The secret of the pending mint commitment is not stored in this
+          browser, so the kitty cannot be revealed from here. Reveal it from the
+          browser which created it, or the commitment expires and its deposit is
+          lost.
+        </Message>
+      ) : (
+        <Message warning>
+          Creating a kitty stores a secret in this browser until the kitty is
+          revealed. Keep the site data of this browser until then, or the
+          commitment cannot be revealed and its deposit is lost.
+        </Message>
+      )}
       <div style={{ overflowWrap: 'break-word' }}>{status}</div>
     </Grid.Column>
   )
@@ -22,19 +22,23 @@ const IMAGES = {
   ),
 }
 
-const dnaToAttributes = dna => {
-  const attribute = (index, type) =>
-    IMAGES[type][dna[index] % IMAGES[type].length]
-
-  return {
-    body: attribute(0, 'body'),
-    eyes: attribute(1, 'eyes'),
-    accessory: attribute(2, 'accessory'),
-    fur: attribute(3, 'fur'),
-    mouth: attribute(4, 'mouth'),
-  }
+// The avatar layer drawn for each gene. The chain decodes the DNA into genes, so that
+// every client agrees on what a kitty looks like.
+const GENE_LAYERS = {
+  BodyColour: 'body',
+  EyeShape: 'eyes',
+  AccentColour: 'accessory',
+  Pattern: 'fur',
+  Mouth: 'mouth',
 }
 
+const traitsToAttributes = traits =>
+  traits.reduce((attributes, { gene, value }) => {
+    const layer = GENE_LAYERS[gene]
+    if (layer) attributes[layer] = IMAGES[layer][value % IMAGES[layer].length]
+    return attributes
+  }, {})
+
 const KittyAvatar = props => {
   const outerStyle = { height: '160px', position: 'relative', width: '50%' }
   const innerStyle = {
@@ -43,11 +47,11 @@ const KittyAvatar = props => {
     top: '3%',
     left: '50%',
   }
-  const { dna } = props
+  const { traits } = props
 
-  if (!dna) return null
+  if (!traits) return null
 
-  const cat = dnaToAttributes(dna)
+  const cat = traitsToAttributes(traits)
   return (
     <div style={outerStyle}>
       <img alt="body" src={cat.body} style={innerStyle} />
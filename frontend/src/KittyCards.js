@@ -1,4 +1,4 @@
-import React from 'react'
+import React, { useEffect, useState } from 'react'
 import {
   Button,
   Card,
@@ -187,14 +187,76 @@ const BuyKitty = props => {
   )
 }
 
+// --- Release Kitty ---
+
+const ReleaseKitty = props => {
+  const { kitty, setStatus } = props
+  const [open, setOpen] = React.useState(false)
+
+  const confirmAndClose = unsub => {
+    setOpen(false)
+    if (unsub && typeof unsub === 'function') unsub()
+  }
+
+  return (
+    <Modal
+      onClose={() => setOpen(false)}
+      onOpen={() => setOpen(true)}
+      open={open}
+      trigger={
+        <Button basic color="red">
+          Release
+        </Button>
+      }
+    >
+      <Modal.Header>Release Kitty</Modal.Header>
+      <Modal.Content>
+        <p>
+          The kitty is burned for good, and its deposits are refunded to you.
+        </p>
+        <Form>
+          <Form.Input fluid label="Kitty ID" readOnly value={kitty.id} />
+        </Form>
+      </Modal.Content>
+      <Modal.Actions>
+        <Button basic color="grey" onClick={() => setOpen(false)}>
+          Cancel
+        </Button>
+        <TxButton
+          label="Release Kitty"
+          type="SIGNED-TX"
+          setStatus={setStatus}
+          onClick={confirmAndClose}
+          attrs={{
+            palletRpc: 'kitties',
+            callable: 'releaseKitty',
+            inputParams: [kitty.id],
+            paramFields: [true],
+          }}
+        />
+      </Modal.Actions>
+    </Modal>
+  )
+}
+
 // --- About Kitty Card ---
 
 const KittyCard = props => {
   const { kitty, setStatus } = props
   const { dna = null, owner = null, gender = null, price = null } = kitty
-  const displayDna = dna && dna.toJSON()
-  const { currentAccount } = useSubstrateState()
+  const { api, currentAccount } = useSubstrateState()
   const isSelf = currentAccount.address === kitty.owner
+  const [traits, setTraits] = useState(null)
+
+  useEffect(() => {
+    let cancelled = false
+    api.rpc.kitties.kittyTraits(dna).then(decoded => {
+      if (!cancelled) setTraits(decoded.toJSON().traits)
+    })
+    return () => {
+      cancelled = true
+    }
+  }, [api, dna])
 
   return (
     <Card>
@@ -203,13 +265,21 @@ const KittyCard = props => {
           Mine
         </Label>
       )}
-      <KittyAvatar dna={dna.toU8a()} />
+      <KittyAvatar traits={traits} />
       <Card.Content>
         <Card.Meta style={{ fontSize: '.9em', overflowWrap: 'break-word' }}>
-          DNA: {displayDna}
+          DNA: {dna}
         </Card.Meta>
         <Card.Description>
           <p style={{ overflowWrap: 'break-word' }}>Gender: {gender}</p>
+          {traits && (
+            <p style={{ overflowWrap: 'break-word' }}>
+              Traits:{' '}
+              {traits
+                .map(({ name, rarity }) => `${name} (${rarity})`)
+                .join(', ')}
+            </p>
+          )}
           <p style={{ overflowWrap: 'break-word' }}>Owner: {owner}</p>
           <p style={{ overflowWrap: 'break-word' }}>
             Price: {price || 'Not For Sale'}
@@ -221,6 +291,7 @@ const KittyCard = props => {
           <>
             <SetPrice kitty={kitty} setStatus={setStatus} />
             <TransferModal kitty={kitty} setStatus={setStatus} />
+            <ReleaseKitty kitty={kitty} setStatus={setStatus} />
           </>
         ) : (
           <>
@@ -1,12 +1,13 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	pallet_substratekitties::Gender, AccountId, AuraConfig, Balance, BalancesConfig, GenesisConfig,
+	GrandpaConfig, Hash, KittiesConfig, KittiesPalletId, KittiesTreasuryAccount, Signature,
+	SudoConfig, SystemConfig, DOLLARS, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_core::{blake2_256, sr25519, Get, Pair, Public};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Verify};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -124,14 +125,40 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
+/// Kitties Alice and Bob start with, a male and a female each so that they can breed right
+/// away. One kitty of each is listed for sale.
+fn genesis_kitties() -> Vec<(AccountId, Hash, Gender, Option<Balance>)> {
+	[
+		("Alice", "Tabby", Gender::Male, None),
+		("Alice", "Mittens", Gender::Female, Some(10 * DOLLARS)),
+		("Bob", "Whiskers", Gender::Male, Some(25 * DOLLARS)),
+		("Bob", "Luna", Gender::Female, None),
+	]
+	.into_iter()
+	.map(|(owner, name, gender, price)| {
+		(
+			get_account_id_from_seed::<sr25519::Public>(owner),
+			Hash::from(blake2_256(name.as_bytes())),
+			gender,
+			price,
+		)
+	})
+	.collect()
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
-	endowed_accounts: Vec<AccountId>,
+	mut endowed_accounts: Vec<AccountId>,
 	_enable_println: bool,
 ) -> GenesisConfig {
+	// The kitties pallet account owns the kitties collection and pays for its deposit.
+	endowed_accounts.push(KittiesPalletId::get().into_account_truncating());
+	// The treasury has to exist to receive protocol fees below the existential deposit.
+	endowed_accounts.push(KittiesTreasuryAccount::get());
+
 	GenesisConfig {
 		system: SystemConfig {
 			// Add Wasm runtime to storage.
@@ -152,5 +179,6 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		kitties: KittiesConfig { kitties: genesis_kitties() },
 	}
 }
@@ -1,4 +1,35 @@
 use sc_cli::RunCmd;
+use std::{path::PathBuf, str::FromStr};
+
+/// How the node authors blocks when started with `--sealing`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block as soon as a transaction enters the pool.
+	Instant,
+	/// Seal blocks only when requested through `engine_createBlock`.
+	Manual,
+	/// Seal a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Self::Instant),
+			"manual" => Ok(Self::Manual),
+			_ => s
+				.strip_prefix("interval=")
+				.and_then(|millis| millis.parse().ok())
+				.filter(|millis| *millis > 0)
+				.map(Self::Interval)
+				.ok_or_else(|| {
+					format!("expected `instant`, `manual` or `interval=<ms>`, found `{}`", s)
+				}),
+		}
+	}
+}
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -7,6 +38,13 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Author blocks with manual-seal instead of Aura and GRANDPA.
+	///
+	/// `instant` seals a block for every transaction, `manual` waits for `engine_createBlock`
+	/// calls and `interval=<ms>` seals a block at a fixed pace.
+	#[arg(long, value_name = "MODE")]
+	pub sealing: Option<Sealing>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -50,4 +88,27 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Render a kitty as an SVG image from its DNA.
+	RenderKitty(RenderKittyCmd),
+}
+
+/// The `render-kitty` command, which draws a kitty with the decoding table of this node.
+#[derive(Debug, clap::Parser)]
+pub struct RenderKittyCmd {
+	/// The DNA of the kitty, as hex with or without a `0x` prefix.
+	#[arg(long)]
+	pub dna: sp_core::Bytes,
+
+	/// The file to write the SVG image to.
+	#[arg(long)]
+	pub out: PathBuf,
+}
+
+impl RenderKittyCmd {
+	/// Renders the kitty and writes it to `out`.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		std::fs::write(&self.out, pallet_substratekitties_render::render(&self.dna))?;
+		Ok(())
+	}
 }
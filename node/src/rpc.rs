@@ -7,8 +7,14 @@
 
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
-use node_template_runtime::{opaque::Block, AccountId, Balance, Index};
+use futures::channel::mpsc;
+use jsonrpsee::{
+	core::RpcResult,
+	types::error::{CallError, ErrorObject},
+	RpcModule,
+};
+use node_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use sc_consensus_manual_seal::EngineCommand;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -16,6 +22,49 @@ use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
 pub use sc_rpc_api::DenyUnsafe;
 
+pub mod nfts;
+pub mod render;
+
+/// Error type of the RPC apis of this node.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The requested page is larger than the runtime API returns.
+	PageTooLarge,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::PageTooLarge => 2,
+		}
+	}
+}
+
+/// Turns a failed call to the runtime into an RPC error with the given message.
+pub fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+/// Refuses pages larger than `max_page_size`, the largest page the runtime API returns.
+pub fn check_page_size(page_size: u32, max_page_size: u32) -> RpcResult<()> {
+	if page_size > max_page_size {
+		return Err(CallError::Custom(ErrorObject::owned(
+			Error::PageTooLarge.into(),
+			"Page size is too large.",
+			Some(format!("The page size can be at most {}", max_page_size)),
+		))
+		.into())
+	}
+	Ok(())
+}
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
 	/// The client instance to use.
@@ -24,6 +73,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Channel for the `engine_*` RPCs, when blocks are sealed manually.
+	pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -36,17 +87,38 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_substratekitties_rpc::KittiesRuntimeApi<
+		Block,
+		AccountId,
+		Hash,
+		Balance,
+		BlockNumber,
+	>,
+	C::Api: nfts::NftsEnumerableApi<Block, AccountId, u32, u32>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use nfts::{Nfts, NftsApiServer};
+	use pallet_substratekitties_rpc::{Kitties, KittiesApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use render::{KittiesRender, KittiesRenderApiServer};
+	use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, command_sink } = deps;
 
 	module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Kitties::new(client.clone()).into_rpc())?;
+	module.merge(KittiesRender::new(client.clone()).into_rpc())?;
+	module.merge(Nfts::new(client).into_rpc())?;
+
+	if let Some(command_sink) = command_sink {
+		// `engine_createBlock` and `engine_finalizeBlock`, for nodes started with
+		// `--sealing manual`.
+		module.merge(ManualSeal::new(command_sink).into_rpc())?;
+	}
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
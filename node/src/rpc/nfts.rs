@@ -0,0 +1,97 @@
+//! RPC methods listing the items of the `KittiesNFT` collection pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_template_runtime::nfts_api::{NftsEnumerableApi, MAX_PAGE_SIZE};
+
+use super::{check_page_size, runtime_error};
+
+/// Paginated item listings on top of the nfts runtime API.
+#[rpc(client, server)]
+pub trait NftsApi<BlockHash, AccountId, CollectionId, ItemId> {
+	/// Returns a page of the items owned by `account`, as `(collection, item)` pairs.
+	#[method(name = "nfts_itemsOf")]
+	fn items_of(
+		&self,
+		account: AccountId,
+		page: u32,
+		page_size: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(CollectionId, ItemId)>>;
+
+	/// Returns a page of the items of `collection`.
+	#[method(name = "nfts_collectionItems")]
+	fn collection_items(
+		&self,
+		collection: CollectionId,
+		page: u32,
+		page_size: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ItemId>>;
+}
+
+/// Provides RPC methods to list the items of the nfts pallet.
+pub struct Nfts<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Nfts<C, P> {
+	/// Creates a new instance of the Nfts RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, CollectionId, ItemId>
+	NftsApiServer<<Block as BlockT>::Hash, AccountId, CollectionId, ItemId> for Nfts<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: NftsEnumerableApi<Block, AccountId, CollectionId, ItemId>,
+	AccountId: Codec + Send + Sync + 'static,
+	CollectionId: Codec + Send + Sync + 'static,
+	ItemId: Codec + Send + Sync + 'static,
+{
+	fn items_of(
+		&self,
+		account: AccountId,
+		page: u32,
+		page_size: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(CollectionId, ItemId)>> {
+		check_page_size(page_size, MAX_PAGE_SIZE)?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.items_of(at_hash, account, page, page_size)
+			.map_err(|e| runtime_error("Unable to query items of account.", e))
+	}
+
+	fn collection_items(
+		&self,
+		collection: CollectionId,
+		page: u32,
+		page_size: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<ItemId>> {
+		check_page_size(page_size, MAX_PAGE_SIZE)?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.collection_items(at_hash, collection, page, page_size)
+			.map_err(|e| runtime_error("Unable to query items of collection.", e))
+	}
+}
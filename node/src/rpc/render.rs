@@ -0,0 +1,55 @@
+//! RPC method rendering kitties as SVG images, for clients without the frontend assets.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use node_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash};
+use pallet_substratekitties_rpc::KittiesRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use super::runtime_error;
+
+/// Kitty images on top of the kitties runtime API.
+#[rpc(client, server)]
+pub trait KittiesRenderApi<BlockHash> {
+	/// Returns the kitty with the given DNA as an SVG document, drawn from the traits the DNA
+	/// decodes to at the given block.
+	#[method(name = "kitties_renderSvg")]
+	fn render_svg(&self, dna: Hash, at: Option<BlockHash>) -> RpcResult<String>;
+}
+
+/// Provides the RPC method rendering kitties.
+pub struct KittiesRender<C> {
+	client: Arc<C>,
+}
+
+impl<C> KittiesRender<C> {
+	/// Creates a new instance of the KittiesRender RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl<C> KittiesRenderApiServer<Hash> for KittiesRender<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: KittiesRuntimeApi<Block, AccountId, Hash, Balance, BlockNumber>,
+{
+	fn render_svg(&self, dna: Hash, at: Option<Hash>) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		// the traits are decoded by the runtime, so that images follow the decoding table of
+		// the chain rather than the one the node was built with
+		let traits = api
+			.kitty_traits(at_hash, dna)
+			.map_err(|e| runtime_error("Unable to query kitty traits.", e))?;
+
+		Ok(pallet_substratekitties_render::render_svg(dna.as_ref(), &traits))
+	}
+}
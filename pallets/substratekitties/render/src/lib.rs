@@ -0,0 +1,220 @@
+//! Renders kitties as SVG images, so that bots, wallets and other clients without the frontend
+//! assets can show them. The image only depends on the traits the DNA decodes to, like the
+//! `KittyAvatar` of the frontend.
+
+use std::fmt::Write;
+
+pub use pallet_substratekitties::traits::{self, Gene, KittyTraits};
+
+/// The width and height of the rendered images, in pixels.
+pub const SIZE: u32 = 240;
+
+// The colours of the values of `Gene::BodyColour`, in the order of the table.
+const BODY_COLOURS: [&str; 15] = [
+	"#f6f3ee", "#c9cdd4", "#f5a88c", "#7b4a32", "#3b3a40", "#f7943c", "#b5653b", "#5fd3c4",
+	"#a777d9", "#d9a6d4", "#ff8c7a", "#b8f0d2", "#f8e7a1", "#f25c9b", "#ffffff",
+];
+
+// The colours of the values of `Gene::AccentColour`, in the order of the table.
+const ACCENT_COLOURS: [&str; 20] = [
+	"#7fa8f5", "#c9a27e", "#ffc8a2", "#cfeefc", "#8a8d91", "#f1e3cf", "#fff1d6", "#2fa86b",
+	"#e3b25b", "#5b6470", "#8c7bd6", "#3ec1c9", "#f49ac1", "#f7c6d9", "#5f7fe0", "#fde2f0",
+	"#ffd83a", "#ff6fa5", "#f9c80e", "#b3122e",
+];
+
+// The shapes of the values of `Gene::EyeShape`, as the radii of the eye, the radius of the
+// pupil, the tilt of the eye in degrees and whether it has a brow.
+const EYES: [(u32, u32, u32, i32, bool); 15] = [
+	(9, 9, 4, 0, false),
+	(10, 7, 4, -12, false),
+	(12, 12, 7, 0, false),
+	(11, 6, 2, 15, false),
+	(10, 6, 4, -20, true),
+	(11, 11, 6, 0, true),
+	(12, 10, 2, 8, false),
+	(10, 8, 5, 25, true),
+	(9, 9, 4, 0, true),
+	(10, 9, 5, -8, true),
+	(13, 13, 3, 0, false),
+	(11, 5, 3, 0, true),
+	(12, 11, 7, -5, false),
+	(10, 4, 3, 0, false),
+	(14, 10, 8, 30, false),
+];
+
+// The paths of the values of `Gene::Mouth`, in the order of the table.
+const MOUTHS: [&str; 10] = [
+	"M108 140 Q114 147 120 140 Q126 147 132 140",
+	"M110 146 Q120 138 130 146",
+	"M106 138 Q120 154 134 138",
+	"M110 144 L130 144",
+	"M104 136 Q120 160 136 136 Z",
+	"M108 140 Q120 150 132 140 M104 150 Q120 172 136 150",
+	"M112 140 Q120 146 128 140 M116 143 L116 150 M124 143 L124 150",
+	"M108 140 Q120 150 132 140 M116 146 Q120 158 124 146",
+	"M106 144 Q113 136 120 144 Q127 152 134 144",
+	"M114 142 Q120 136 126 142 Q120 148 114 142 Z",
+];
+
+/// Renders the kitty with the given DNA, decoded with the current table.
+pub fn render(dna: &[u8]) -> String {
+	render_svg(dna, &traits::decode(dna))
+}
+
+/// Renders a kitty as an SVG document from its DNA and the traits it decodes to. Genes missing
+/// from `traits` are drawn with their first value, and values past the end of the palettes of
+/// this crate wrap around.
+pub fn render_svg(dna: &[u8], traits: &KittyTraits) -> String {
+	let body = BODY_COLOURS[value_of(traits, Gene::BodyColour) % BODY_COLOURS.len()];
+	let accent = ACCENT_COLOURS[value_of(traits, Gene::AccentColour) % ACCENT_COLOURS.len()];
+	let (eye_rx, eye_ry, pupil, tilt, brow) = EYES[value_of(traits, Gene::EyeShape) % EYES.len()];
+	let mouth = MOUTHS[value_of(traits, Gene::Mouth) % MOUTHS.len()];
+
+	let mut svg = String::new();
+	// writing to a `String` cannot fail
+	let _ = writeln!(
+		svg,
+		r#"<svg xmlns="http://www.w3.org/2000/svg" width="{SIZE}" height="{SIZE}" viewBox="0 0 240 240">"#
+	);
+	let _ = writeln!(svg, "<title>{}</title>", escape(&title(dna, traits)));
+	let _ =
+		writeln!(svg, r#"<rect width="240" height="240" rx="24" fill="{accent}" opacity="0.25"/>"#);
+
+	// body, ears and head
+	let _ = writeln!(svg, r##"<g fill="{body}" stroke="#2b2b2b" stroke-width="3">"##);
+	let _ = writeln!(svg, r#"<ellipse cx="120" cy="180" rx="72" ry="48"/>"#);
+	let _ = writeln!(svg, r#"<polygon points="66,82 78,28 112,58"/>"#);
+	let _ = writeln!(svg, r#"<polygon points="174,82 162,28 128,58"/>"#);
+	let _ = writeln!(svg, r#"<circle cx="120" cy="112" r="60"/>"#);
+	let _ = writeln!(svg, "</g>");
+
+	write_pattern(&mut svg, value_of(traits, Gene::Pattern), accent);
+
+	// eyes, mirrored on both sides of the face
+	for (cx, side) in [(98, -1), (142, 1)] {
+		let _ = writeln!(svg, r#"<g transform="rotate({} {cx} 104)">"#, tilt * side);
+		let _ = writeln!(
+			svg,
+			r##"<ellipse cx="{cx}" cy="104" rx="{eye_rx}" ry="{eye_ry}" fill="#ffffff" stroke="#2b2b2b" stroke-width="2"/>"##
+		);
+		let _ = writeln!(svg, r##"<circle cx="{cx}" cy="104" r="{pupil}" fill="#2b2b2b"/>"##);
+		if brow {
+			let _ = writeln!(
+				svg,
+				r##"<path d="M{} {} l{} -4" stroke="#2b2b2b" stroke-width="3" fill="none"/>"##,
+				cx - eye_rx as i32,
+				104 - eye_ry as i32 - 6,
+				eye_rx * 2
+			);
+		}
+		let _ = writeln!(svg, "</g>");
+	}
+
+	// nose, whiskers and mouth
+	let _ = writeln!(svg, r#"<polygon points="114,126 126,126 120,133" fill="{accent}"/>"#);
+	let _ = writeln!(
+		svg,
+		r##"<path d="M80 130 L44 122 M80 136 L42 138 M160 130 L196 122 M160 136 L198 138" stroke="#2b2b2b" stroke-width="2"/>"##
+	);
+	let _ = writeln!(
+		svg,
+		r##"<path d="{mouth}" fill="none" stroke="#2b2b2b" stroke-width="3" stroke-linecap="round"/>"##
+	);
+	let _ = writeln!(svg, "</svg>");
+	svg
+}
+
+// Returns the value of `gene` in `traits`, or the first value if the gene is missing.
+fn value_of(traits: &KittyTraits, gene: Gene) -> usize {
+	traits
+		.traits
+		.iter()
+		.find(|kitty_trait| kitty_trait.gene == gene)
+		.map_or(0, |kitty_trait| kitty_trait.value as usize)
+}
+
+// Draws the values of `Gene::Pattern` over the body and the head, in the accent colour.
+fn write_pattern(svg: &mut String, pattern: usize, accent: &str) {
+	let shapes = match pattern % 10 {
+		// Totesbasic
+		0 => "",
+		// Calicool
+		1 =>
+			r#"<circle cx="84" cy="176" r="14"/><circle cx="150" cy="192" r="18"/><circle cx="150" cy="76" r="12"/>"#,
+		// Luckystripe
+		2 =>
+			r#"<rect x="96" y="134" width="10" height="90"/><rect x="116" y="132" width="10" height="94"/><rect x="136" y="134" width="10" height="90"/>"#,
+		// Spock
+		3 => r#"<path d="M120 52 A60 60 0 0 1 120 172 Z"/>"#,
+		// Tigerpunk
+		4 =>
+			r#"<path d="M104 54 L110 80 L116 54 Z M124 54 L130 80 L136 54 Z M62 110 L88 116 L62 122 Z M178 110 L152 116 L178 122 Z"/>"#,
+		// Rascal
+		5 => r#"<rect x="74" y="92" width="92" height="24" rx="12"/>"#,
+		// Ganado
+		6 =>
+			r#"<ellipse cx="80" cy="90" rx="26" ry="20"/><ellipse cx="160" cy="196" rx="30" ry="18"/>"#,
+		// Jaguar
+		7 =>
+			r#"<g fill="none" stroke-width="4"><circle cx="84" cy="170" r="8"/><circle cx="112" cy="196" r="8"/><circle cx="156" cy="174" r="8"/><circle cx="104" cy="70" r="6"/><circle cx="140" cy="72" r="6"/></g>"#,
+		// Dippedcone
+		8 => r#"<path d="M48 186 A72 48 0 0 0 192 186 Z"/>"#,
+		// Thunderstruck
+		_ => r#"<path d="M132 52 L108 112 L126 112 L104 172 L146 96 L126 96 L144 52 Z"/>"#,
+	};
+	if !shapes.is_empty() {
+		let _ =
+			writeln!(svg, r#"<g fill="{accent}" stroke="{accent}" opacity="0.85">{shapes}</g>"#);
+	}
+}
+
+// Describes the kitty for the title of the image, which screen readers and tooltips show.
+fn title(dna: &[u8], traits: &KittyTraits) -> String {
+	let names: Vec<_> = traits
+		.traits
+		.iter()
+		.map(|kitty_trait| String::from_utf8_lossy(&kitty_trait.name).into_owned())
+		.collect();
+	let hex: String = dna.iter().map(|byte| format!("{:02x}", byte)).collect();
+	format!("Kitty 0x{}: {}", hex, names.join(", "))
+}
+
+fn escape(text: &str) -> String {
+	text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn renders_the_traits_of_the_dna() {
+		let svg = render(&[0; 32]);
+		assert!(svg.starts_with("<svg"));
+		assert!(svg.ends_with("</svg>\n"));
+		assert!(svg.contains(BODY_COLOURS[0]));
+		assert!(svg.contains("Cotton"));
+
+		// the rarest values are drawn differently, and the same DNA always gives the same image
+		let rare = render(&[255; 32]);
+		assert!(rare.contains(BODY_COLOURS[14]));
+		assert!(rare.contains("Cloudwhite"));
+		assert_ne!(svg, rare);
+		assert_eq!(rare, render(&[255; 32]));
+	}
+
+	#[test]
+	fn palettes_cover_the_current_table() {
+		let table = traits::table(traits::TRAITS_VERSION).unwrap();
+		for gene_table in table {
+			let drawn = match gene_table.gene {
+				Gene::BodyColour => BODY_COLOURS.len(),
+				Gene::EyeShape => EYES.len(),
+				Gene::AccentColour => ACCENT_COLOURS.len(),
+				Gene::Pattern => 10,
+				Gene::Mouth => MOUTHS.len(),
+			};
+			assert_eq!(drawn, gene_table.values.len());
+		}
+	}
+}
@@ -0,0 +1,184 @@
+//! RPC interface for querying the kitties of pallet-substratekitties.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_substratekitties_runtime_api::{
+	KittiesApi as KittiesRuntimeApi, KittyInfo, KittyRarity, KittyTraits, MAX_PAGE_SIZE,
+};
+
+#[rpc(client, server)]
+pub trait KittiesApi<BlockHash, AccountId, Hash, Balance, BlockNumber> {
+	/// Returns the kitty with the given DNA.
+	#[method(name = "kitties_kitty")]
+	fn kitty(
+		&self,
+		dna: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<KittyInfo<AccountId, Hash, Balance, BlockNumber>>>;
+
+	/// Returns every kitty owned by `account`.
+	#[method(name = "kitties_kittiesOf")]
+	fn kitties_of(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>>;
+
+	/// Returns a page of the kitties listed at a fixed price.
+	#[method(name = "kitties_kittiesForSale")]
+	fn kitties_for_sale(
+		&self,
+		page: u32,
+		page_size: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>>;
+
+	/// Returns the number of kitties in existence.
+	#[method(name = "kitties_totalCount")]
+	fn total_count(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+
+	/// Returns the traits the given DNA decodes to.
+	#[method(name = "kitties_kittyTraits")]
+	fn kitty_traits(&self, dna: Hash, at: Option<BlockHash>) -> RpcResult<KittyTraits>;
+
+	/// Returns how rare the traits of the kitty with the given DNA are.
+	#[method(name = "kitties_kittyRarity")]
+	fn kitty_rarity(&self, dna: Hash, at: Option<BlockHash>) -> RpcResult<Option<KittyRarity>>;
+}
+
+/// Provides RPC methods to query the kitties of pallet-substratekitties.
+pub struct Kitties<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Kitties<C, P> {
+	/// Creates a new instance of the Kitties RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The requested page is larger than `MAX_PAGE_SIZE`.
+	PageTooLarge,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::PageTooLarge => 2,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+fn check_page_size(page_size: u32) -> RpcResult<()> {
+	if page_size > MAX_PAGE_SIZE {
+		return Err(CallError::Custom(ErrorObject::owned(
+			Error::PageTooLarge.into(),
+			"Page size is too large.",
+			Some(format!("The page size can be at most {}", MAX_PAGE_SIZE)),
+		))
+		.into())
+	}
+	Ok(())
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Hash, Balance, BlockNumber>
+	KittiesApiServer<<Block as BlockT>::Hash, AccountId, Hash, Balance, BlockNumber>
+	for Kitties<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: KittiesRuntimeApi<Block, AccountId, Hash, Balance, BlockNumber>,
+	AccountId: Codec + Send + Sync + 'static,
+	Hash: Codec + Send + Sync + 'static,
+	Balance: Codec + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+{
+	fn kitty(
+		&self,
+		dna: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<KittyInfo<AccountId, Hash, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.kitty(at_hash, dna).map_err(|e| runtime_error("Unable to query kitty.", e))
+	}
+
+	fn kitties_of(
+		&self,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.kitties_of(at_hash, account)
+			.map_err(|e| runtime_error("Unable to query kitties of account.", e))
+	}
+
+	fn kitties_for_sale(
+		&self,
+		page: u32,
+		page_size: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>> {
+		check_page_size(page_size)?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.kitties_for_sale(at_hash, page, page_size)
+			.map_err(|e| runtime_error("Unable to query kitties for sale.", e))
+	}
+
+	fn total_count(&self, at: Option<Block::Hash>) -> RpcResult<u64> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.total_count(at_hash)
+			.map_err(|e| runtime_error("Unable to query kitties count.", e))
+	}
+
+	fn kitty_traits(&self, dna: Hash, at: Option<Block::Hash>) -> RpcResult<KittyTraits> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.kitty_traits(at_hash, dna)
+			.map_err(|e| runtime_error("Unable to query kitty traits.", e))
+	}
+
+	fn kitty_rarity(&self, dna: Hash, at: Option<Block::Hash>) -> RpcResult<Option<KittyRarity>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.kitty_rarity(at_hash, dna)
+			.map_err(|e| runtime_error("Unable to query kitty rarity.", e))
+	}
+}
@@ -0,0 +1,81 @@
+//! Runtime API definition for querying the kitties of pallet-substratekitties.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use pallet_substratekitties::{BalanceOf, Config, Gender, Kitty};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub use pallet_substratekitties::traits::{Gene, KittyRarity, KittyTrait, KittyTraits, Rarity};
+
+/// The largest page `kitties_for_sale` returns.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// A kitty along with its current owner, as returned by the runtime API.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct KittyInfo<AccountId, Hash, Balance, BlockNumber> {
+	pub dna: Hash,
+	pub owner: AccountId,
+	pub price: Option<Balance>,
+	pub gender: Gender,
+	pub generation: u32,
+	pub parents: Option<(Hash, Hash)>,
+	pub born_at: BlockNumber,
+	pub ready_at: BlockNumber,
+	pub cooldown_index: u32,
+}
+
+impl<T: Config> From<(Kitty<T>, T::AccountId)>
+	for KittyInfo<T::AccountId, T::Hash, BalanceOf<T>, T::BlockNumber>
+{
+	fn from((kitty, owner): (Kitty<T>, T::AccountId)) -> Self {
+		KittyInfo {
+			dna: kitty.dna,
+			owner,
+			price: kitty.price,
+			gender: kitty.gender,
+			generation: kitty.generation,
+			parents: kitty.parents,
+			born_at: kitty.born_at,
+			ready_at: kitty.ready_at,
+			cooldown_index: kitty.cooldown_index,
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait KittiesApi<AccountId, Hash, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The kitty with the given DNA.
+		fn kitty(dna: Hash) -> Option<KittyInfo<AccountId, Hash, Balance, BlockNumber>>;
+
+		/// Every kitty owned by `account`.
+		fn kitties_of(account: AccountId) -> Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>;
+
+		/// A page of the kitties listed at a fixed price, with at most `MAX_PAGE_SIZE` kitties
+		/// per page.
+		fn kitties_for_sale(
+			page: u32,
+			page_size: u32,
+		) -> Vec<KittyInfo<AccountId, Hash, Balance, BlockNumber>>;
+
+		/// The number of kitties in existence.
+		fn total_count() -> u64;
+
+		/// The traits `dna` decodes to with the current decoding table, whether or not a kitty
+		/// with this DNA exists.
+		fn kitty_traits(dna: Hash) -> KittyTraits;
+
+		/// How rare the traits of the kitty with the given DNA are among all kitties.
+		fn kitty_rarity(dna: Hash) -> Option<KittyRarity>;
+	}
+}
@@ -1,35 +1,447 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-substratekitties
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as Kitties;
 use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::traits::Bounded,
+	sp_std::vec,
+	traits::{Currency, Get, Hooks},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 
+// Gives `who` enough funds to pay for any number of kitties, deposits and fees.
+fn fund_account<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+// Returns an account with enough funds to use the kitties pallet. The account of the pallet is
+// funded as well, since it pays the deposit of the kitties collection.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	fund_account::<T>(&Kitties::<T>::account_id());
+	let who = account(name, index, 0);
+	fund_account::<T>(&who);
+	who
+}
+
+// Mints a generation zero kitty of the given gender, with a DNA derived from `seed`.
+fn mint_kitty<T: Config>(owner: &T::AccountId, seed: u32, gender: Gender) -> T::Hash {
+	let mut dna = T::Hashing::hash_of(&(b"kitty", seed));
+	dna.as_mut()[0] = match gender {
+		Gender::Male => 0,
+		Gender::Female => 1,
+	};
+	Kitties::<T>::mint(owner, dna, None, 0).expect("the owner can own another kitty");
+	dna
+}
+
+// Mints `count` kitties to `owner`, so that minting another one has to walk over all of them.
+fn mint_kitties<T: Config>(owner: &T::AccountId, seed: u32, count: u32) {
+	for i in 0..count {
+		mint_kitty::<T>(owner, seed + i, Gender::Male);
+	}
+}
+
+// Returns a name or bio made of `len` times `byte`.
+fn filled<S: Get<u32>>(byte: u8, len: u32) -> BoundedVec<u8, S> {
+	BoundedVec::try_from(vec![byte; len as usize]).expect("the length is within the bound")
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
+	// The recipient already owns `n` kitties.
+	#[benchmark]
+	fn transfer(n: Linear<0, { T::MaxKittiesOwned::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let recipient = funded_account::<T>("recipient", 0);
+		mint_kitties::<T>(&recipient, 1, n);
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+		// worst case: the kitty is listed and has to be unlocked
+		Kitties::<T>::set_price(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty,
+			Some(1u32.into()),
+		)?;
+
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(caller), recipient.clone(), kitty);
+
+		assert_eq!(Kitties::<T>::owner_of(&kitty), Some(recipient));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+		let price: BalanceOf<T> = 100u32.into();
+
+		#[extrinsic_call]
+		set_price(RawOrigin::Signed(caller), kitty, Some(price));
+
+		assert_eq!(Kitties::<T>::kitties(kitty).map(|kitty| kitty.price), Some(Some(price)));
+	}
+
+	#[benchmark]
+	fn buy_kitty() -> Result<(), BenchmarkError> {
+		let seller = funded_account::<T>("seller", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		let price: BalanceOf<T> = 100u32.into();
+		Kitties::<T>::set_price(RawOrigin::Signed(seller).into(), kitty, Some(price))?;
+		// the treasury has to exist to receive fees below the existential deposit
+		fund_account::<T>(&T::TreasuryAccount::get());
+
+		#[extrinsic_call]
+		buy_kitty(RawOrigin::Signed(caller.clone()), kitty, price);
+
+		assert_eq!(Kitties::<T>::owner_of(&kitty), Some(caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn breed_kitty() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		mint_kitties::<T>(&caller, 0, T::MaxKittiesOwned::get() - 3);
+		let male = mint_kitty::<T>(&caller, T::MaxKittiesOwned::get(), Gender::Male);
+		let female = mint_kitty::<T>(&caller, T::MaxKittiesOwned::get() + 1, Gender::Female);
+
+		let commitment = T::Hashing::hash_of(&(&caller, T::Hash::default()));
+
+		#[extrinsic_call]
+		breed_kitty(RawOrigin::Signed(caller.clone()), male, female, commitment);
+
+		assert!(MintCommitments::<T>::contains_key(caller));
+	}
+
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
+	fn offer_sire() {
 		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		let male = mint_kitty::<T>(&caller, 0, Gender::Male);
+		let fee: BalanceOf<T> = 100u32.into();
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		offer_sire(RawOrigin::Signed(caller.clone()), male, Some(fee));
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Kitties::<T>::sire_fee(male), Some((caller, fee)));
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn breed_with_sire() -> Result<(), BenchmarkError> {
+		let sire_owner = funded_account::<T>("sire_owner", 0);
 		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		mint_kitties::<T>(&caller, 0, T::MaxKittiesOwned::get() - 2);
+		let female = mint_kitty::<T>(&caller, T::MaxKittiesOwned::get(), Gender::Female);
+		let male = mint_kitty::<T>(&sire_owner, T::MaxKittiesOwned::get() + 1, Gender::Male);
+		Kitties::<T>::offer_sire(RawOrigin::Signed(sire_owner).into(), male, Some(100u32.into()))?;
+		let commitment = T::Hashing::hash_of(&(&caller, T::Hash::default()));
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		breed_with_sire(RawOrigin::Signed(caller.clone()), female, male, commitment);
+
+		assert!(MintCommitments::<T>::contains_key(caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+
+		#[extrinsic_call]
+		create_auction(
+			RawOrigin::Signed(caller),
+			kitty,
+			AuctionKind::English,
+			100u32.into(),
+			100u32.into(),
+			T::MaxAuctionDuration::get(),
+		);
+
+		assert!(Auctions::<T>::contains_key(kitty));
+	}
+
+	// The worst case is a bid on a Dutch auction, which sells the kitty right away.
+	#[benchmark]
+	fn bid() -> Result<(), BenchmarkError> {
+		let seller = funded_account::<T>("seller", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&T::TreasuryAccount::get());
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		let price: BalanceOf<T> = 100u32.into();
+		Kitties::<T>::create_auction(
+			RawOrigin::Signed(seller).into(),
+			kitty,
+			AuctionKind::Dutch,
+			price,
+			price,
+			T::MaxAuctionDuration::get(),
+		)?;
+
+		#[extrinsic_call]
+		bid(RawOrigin::Signed(caller.clone()), kitty, price);
+
+		assert_eq!(Kitties::<T>::owner_of(&kitty), Some(caller));
+		Ok(())
+	}
+
+	// The worst case is an English auction sold to its best bid.
+	#[benchmark]
+	fn settle_auction() -> Result<(), BenchmarkError> {
+		let seller = funded_account::<T>("seller", 0);
+		let bidder = funded_account::<T>("bidder", 0);
+		fund_account::<T>(&T::TreasuryAccount::get());
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		let price: BalanceOf<T> = 100u32.into();
+		let duration = T::MaxAuctionDuration::get();
+		Kitties::<T>::create_auction(
+			RawOrigin::Signed(seller).into(),
+			kitty,
+			AuctionKind::English,
+			price,
+			price,
+			duration,
+		)?;
+		Kitties::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), kitty, price)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + duration,
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		settle_auction(RawOrigin::Signed(caller), kitty);
+
+		assert_eq!(Kitties::<T>::owner_of(&kitty), Some(bidder));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn make_offer() {
+		let seller = funded_account::<T>("seller", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		let amount: BalanceOf<T> = 100u32.into();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		make_offer(RawOrigin::Signed(caller.clone()), kitty, amount, expires_at);
+
+		assert_eq!(Offers::<T>::get(kitty, caller), Some((amount, expires_at)));
+	}
+
+	#[benchmark]
+	fn accept_offer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let buyer = funded_account::<T>("buyer", 0);
+		fund_account::<T>(&T::TreasuryAccount::get());
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kitties::<T>::make_offer(
+			RawOrigin::Signed(buyer.clone()).into(),
+			kitty,
+			100u32.into(),
+			expires_at,
+		)?;
+
+		#[extrinsic_call]
+		accept_offer(RawOrigin::Signed(caller), kitty, buyer.clone());
+
+		assert_eq!(Kitties::<T>::owner_of(&kitty), Some(buyer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_offer() -> Result<(), BenchmarkError> {
+		let seller = funded_account::<T>("seller", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Kitties::<T>::make_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty,
+			100u32.into(),
+			expires_at,
+		)?;
+
+		#[extrinsic_call]
+		withdraw_offer(RawOrigin::Signed(caller.clone()), kitty);
+
+		assert_eq!(Offers::<T>::get(kitty, caller), None);
+		Ok(())
+	}
+
+	// The caller is left with room for exactly one more kitty.
+	#[benchmark]
+	fn commit_mint() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		mint_kitties::<T>(&caller, 0, T::MaxKittiesOwned::get() - 1);
+		let commitment = T::Hashing::hash_of(&(&caller, T::Hash::default()));
+
+		#[extrinsic_call]
+		commit_mint(RawOrigin::Signed(caller.clone()), commitment);
+
+		assert!(MintCommitments::<T>::contains_key(caller));
+	}
+
+	// The commitment mints `n` kitties, and is revealed from a full list of commitments
+	// expiring with it.
+	#[benchmark]
+	fn reveal_mint(n: Linear<1, { T::MaxMintPerCommit::get() }>) -> Result<(), BenchmarkError> {
+		for i in 1..T::MaxMintCommitmentsPerBlock::get() {
+			let other = funded_account::<T>("committer", i);
+			let commitment = T::Hashing::hash_of(&(&other, T::Hash::default()));
+			Kitties::<T>::commit_mint(RawOrigin::Signed(other).into(), commitment)?;
+		}
+		fund_account::<T>(&Kitties::<T>::account_id());
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let secret = T::Hash::default();
+		let commitment = T::Hashing::hash_of(&(&caller, &secret));
+		Kitties::<T>::create_kitties(RawOrigin::Signed(caller.clone()).into(), n, commitment)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::MintRevealDelay::get() + 1u32.into(),
+		);
+
+		#[extrinsic_call]
+		reveal_mint(RawOrigin::Signed(caller.clone()), secret);
+
+		assert_eq!(Kitties::<T>::owned_count(&caller), n);
+		assert!(!MintCommitments::<T>::contains_key(caller));
+		Ok(())
+	}
+
+	// A listed kitty is unlocked before it is burned.
+	#[benchmark]
+	fn release_kitty() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fund_account::<T>(&Kitties::<T>::account_id());
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+		Kitties::<T>::set_price(
+			RawOrigin::Signed(caller.clone()).into(),
+			kitty,
+			Some(100u32.into()),
+		)?;
+
+		#[extrinsic_call]
+		release_kitty(RawOrigin::Signed(caller.clone()), kitty);
+
+		assert_eq!(Kitties::<T>::kitties(kitty), None);
+		assert_eq!(Kitties::<T>::owned_count(&caller), 0);
+		Ok(())
+	}
+
+	// The kitty was named by its previous owner, whose deposit is released.
+	#[benchmark]
+	fn set_kitty_name() -> Result<(), BenchmarkError> {
+		let seller = funded_account::<T>("seller", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty = mint_kitty::<T>(&seller, 0, Gender::Male);
+		Kitties::<T>::set_kitty_name(
+			RawOrigin::Signed(seller.clone()).into(),
+			kitty,
+			filled(b'a', 1),
+			None,
+		)?;
+		Kitties::<T>::transfer(RawOrigin::Signed(seller).into(), caller.clone(), kitty)?;
+		let name: KittyName<T> = filled(b'b', T::MaxNameLen::get());
+
+		#[extrinsic_call]
+		set_kitty_name(
+			RawOrigin::Signed(caller),
+			kitty,
+			name.clone(),
+			Some(filled(b'b', T::MaxBioLen::get())),
+		);
+
+		assert_eq!(Kitties::<T>::kitty_named(name), Some(kitty));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_clear_name() -> Result<(), BenchmarkError> {
+		let caller = funded_account::<T>("owner", 0);
+		let kitty = mint_kitty::<T>(&caller, 0, Gender::Male);
+		Kitties::<T>::set_kitty_name(
+			RawOrigin::Signed(caller).into(),
+			kitty,
+			filled(b'a', T::MaxNameLen::get()),
+			Some(filled(b'a', T::MaxBioLen::get())),
+		)?;
+
+		#[extrinsic_call]
+		force_clear_name(RawOrigin::Root, kitty);
+
+		assert_eq!(Kitties::<T>::kitty_metadata(kitty), None);
+		Ok(())
+	}
+
+	// `a` English auctions are sold to their best bid, and `c` mint commitments expire, in the
+	// same block.
+	#[benchmark]
+	fn on_initialize(
+		a: Linear<0, { T::MaxAuctionsPerBlock::get() }>,
+		c: Linear<0, { T::MaxMintCommitmentsPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		fund_account::<T>(&T::TreasuryAccount::get());
+		let price: BalanceOf<T> = 100u32.into();
+		let duration = T::MaxAuctionDuration::get();
+		let end = frame_system::Pallet::<T>::block_number() + duration;
+		for i in 0..a {
+			let seller = funded_account::<T>("seller", i);
+			let bidder = funded_account::<T>("bidder", i);
+			let kitty = mint_kitty::<T>(&seller, i, Gender::Male);
+			Kitties::<T>::create_auction(
+				RawOrigin::Signed(seller).into(),
+				kitty,
+				AuctionKind::English,
+				price,
+				price,
+				duration,
+			)?;
+			Kitties::<T>::bid(RawOrigin::Signed(bidder).into(), kitty, price)?;
+		}
+		// the commitments are made so that they expire when the auctions end
+		frame_system::Pallet::<T>::set_block_number(end - T::MintCommitmentExpiry::get());
+		for i in 0..c {
+			let committer = funded_account::<T>("committer", i);
+			let commitment = T::Hashing::hash_of(&(&committer, T::Hash::default()));
+			Kitties::<T>::commit_mint(RawOrigin::Signed(committer).into(), commitment)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(end);
+
+		#[block]
+		{
+			Kitties::<T>::on_initialize(end);
+		}
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert!(Auctions::<T>::iter_keys().next().is_none());
+		assert!(MintCommitments::<T>::iter_keys().next().is_none());
+		Ok(())
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Kitties, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,67 @@
+//! Implementations for `nonfungible` traits, so that other pallets can take kitties as any
+//! other NFT. Kitties are identified by their DNA.
+
+use super::*;
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::tokens::nonfungible::{Inspect, Mutate, Transfer},
+};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type ItemId = T::Hash;
+
+	fn owner(kitty_dna: &T::Hash) -> Option<T::AccountId> {
+		Self::owner_of(kitty_dna)
+	}
+
+	/// Returns the SCALE encoded gender, price or generation of a kitty.
+	fn attribute(kitty_dna: &T::Hash, key: &[u8]) -> Option<Vec<u8>> {
+		let kitty = Self::kitties(kitty_dna)?;
+		match key {
+			b"gender" => Some(kitty.gender.encode()),
+			b"price" => Some(kitty.price.encode()),
+			b"generation" => Some(kitty.generation.encode()),
+			_ => None,
+		}
+	}
+
+	/// Kitties can be transferred unless they are being auctioned. Listed kitties are taken
+	/// off sale when they are transferred.
+	fn can_transfer(kitty_dna: &T::Hash) -> bool {
+		Kitties::<T>::contains_key(kitty_dna) && !Auctions::<T>::contains_key(kitty_dna)
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	/// Mints a generation zero kitty with the given DNA to `who`.
+	fn mint_into(kitty_dna: &T::Hash, who: &T::AccountId) -> DispatchResult {
+		Self::mint(who, *kitty_dna, None, 0)?;
+		Self::deposit_event(Event::Created { kitty: *kitty_dna, owner: who.clone() });
+		Ok(())
+	}
+
+	/// Burns a kitty which is not being auctioned, releasing its deposit.
+	fn burn(kitty_dna: &T::Hash, maybe_check_owner: Option<&T::AccountId>) -> DispatchResult {
+		let owner = Self::owner_of(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+		Self::do_burn(kitty_dna, maybe_check_owner)?;
+		Self::deposit_event(Event::Released { kitty: *kitty_dna, owner });
+		Ok(())
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(kitty_dna: &T::Hash, destination: &T::AccountId) -> DispatchResult {
+		let mut kitty = Self::kitties(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+		let from = Self::owner_of(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+		Self::do_transfer(&mut kitty, from.clone(), destination.clone())?;
+
+		Self::deposit_event(Event::Transferred {
+			from,
+			to: destination.clone(),
+			kitty: *kitty_dna,
+		});
+
+		Ok(())
+	}
+}
@@ -5,35 +5,71 @@
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod impl_nonfungible;
+pub mod migration;
+pub mod traits;
 pub mod weights;
 pub use weights::*;
 
-use frame_support::sp_runtime::traits::Hash;
+use frame_support::{
+	sp_runtime::{
+		traits::{
+			AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, Hash, One, Saturating,
+			UniqueSaturatedInto, Zero,
+		},
+		Perbill,
+	},
+	sp_std::vec::Vec,
+	traits::{tokens::nonfungibles_v2, ExistenceRequirement, ReservableCurrency},
+	PalletId,
+};
+use pallet_nfts::{CollectionConfig, CollectionSettings, ItemConfig, ItemSettings, MintSettings};
+
+#[cfg(any(feature = "try-runtime", test))]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::kitties";
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_support::traits::{Currency, Randomness};
+	use frame_support::{pallet_prelude::*, traits::Currency};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// Allows easy access our Pallet's `Balance` type. Comes from `Currency` interface.
-	type BalanceOf<T> =
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	// The configuration of the `pallet_nfts` collection the kitties are minted into.
+	pub type CollectionConfigFor<T> =
+		CollectionConfig<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::CollectionId>;
+
 	// The Gender type used in the `Kitty` struct
 	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	pub enum Gender {
 		Male,
 		Female,
 	}
 
-	// Struct for holding kitty information
+	// Struct for holding kitty information. The owner of a kitty is the owner of its item in
+	// the kitties collection of `T::Nfts`.
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
 	#[scale_info(skip_type_params(T))]
 	pub struct Kitty<T: Config> {
@@ -41,7 +77,16 @@ pub mod pallet {
 		pub dna: T::Hash,
 		pub price: Option<BalanceOf<T>>,
 		pub gender: Gender,
-		pub owner: T::AccountId,
+		// The number of breeding steps between this kitty and its oldest ancestor.
+		pub generation: u32,
+		// The DNA of both parents, or `None` for a kitty which was not bred.
+		pub parents: Option<(T::Hash, T::Hash)>,
+		// The block number at which the kitty was born.
+		pub born_at: BlockNumberFor<T>,
+		// The block number from which the kitty is allowed to breed again.
+		pub ready_at: BlockNumberFor<T>,
+		// Grows every time the kitty breeds, and sets the length of its next cooldown.
+		pub cooldown_index: u32,
 	}
 
 	impl<T: Config> Kitty<T> {
@@ -52,11 +97,105 @@ pub mod pallet {
 			}
 		}
 
-		fn new(dna: T::Hash, owner: T::AccountId) -> Self {
-			Kitty { dna, gender: Kitty::<T>::generate_gender(dna), owner, price: None }
+		fn new(dna: T::Hash, parents: Option<(T::Hash, T::Hash)>, generation: u32) -> Self {
+			let now = <frame_system::Pallet<T>>::block_number();
+			Kitty {
+				dna,
+				gender: Kitty::<T>::generate_gender(dna),
+				price: None,
+				generation,
+				parents,
+				born_at: now,
+				ready_at: now,
+				// later generations start out slower, like their parents
+				cooldown_index: (generation / 2).min(T::MaxCooldownIndex::get()),
+			}
+		}
+
+		/// Puts the kitty on cooldown after breeding. Each breeding doubles the length of the
+		/// next cooldown, up to `BaseBreedingCooldown * 2 ^ MaxCooldownIndex` blocks.
+		fn start_cooldown(&mut self, now: BlockNumberFor<T>) {
+			let cooldown = T::BaseBreedingCooldown::get()
+				.saturating_mul(2u32.saturating_pow(self.cooldown_index).into());
+			self.ready_at = now.saturating_add(cooldown);
+			self.cooldown_index =
+				self.cooldown_index.saturating_add(1).min(T::MaxCooldownIndex::get());
+		}
+	}
+
+	// The kinds of auction a kitty can be sold in.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AuctionKind {
+		// The price goes up with every bid, and the best bid wins once the auction ends.
+		English,
+		// The price goes down every block, and the first bid wins right away.
+		Dutch,
+	}
+
+	// Struct for holding the auction of a kitty. The item of the kitty is transfer-locked
+	// while the auction runs.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Auction<T: Config> {
+		pub seller: T::AccountId,
+		pub kind: AuctionKind,
+		// The lowest first bid of an English auction, or the opening price of a Dutch auction.
+		pub start_price: BalanceOf<T>,
+		// The lowest price the kitty sells for. A Dutch auction ends at this price.
+		pub reserve: BalanceOf<T>,
+		pub start: BlockNumberFor<T>,
+		pub end: BlockNumberFor<T>,
+		// The best bid of an English auction so far, reserved from the bidder.
+		pub best_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	impl<T: Config> Auction<T> {
+		/// The price a Dutch auction asks for at block `now`, going down linearly from
+		/// `start_price` at `start` to `reserve` at `end`.
+		pub fn dutch_price(&self, now: BlockNumberFor<T>) -> BalanceOf<T> {
+			let elapsed: u32 = now.saturating_sub(self.start).unique_saturated_into();
+			let duration: u32 = self.end.saturating_sub(self.start).unique_saturated_into();
+			let drop = Perbill::from_rational(elapsed, duration) *
+				self.start_price.saturating_sub(self.reserve);
+			self.start_price.saturating_sub(drop)
 		}
 	}
 
+	// What a mint commitment mints once it is revealed.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PendingMint<Hash> {
+		// The given number of generation zero kitties.
+		Kitties(u32),
+		// The child of two parents, which were put on cooldown when the commitment was made.
+		Child { parents: (Hash, Hash), generation: u32 },
+	}
+
+	// Struct for holding a mint commitment, made at block `committed_at`. The deposit is
+	// reserved from the account which made the commitment until it is revealed.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct MintCommitment<T: Config> {
+		pub commitment: T::Hash,
+		pub committed_at: BlockNumberFor<T>,
+		pub deposit: BalanceOf<T>,
+		pub mint: PendingMint<T::Hash>,
+	}
+
+	// The name of a kitty, unique across all kitties, and its optional bio.
+	pub type KittyName<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+	pub type KittyBio<T> = BoundedVec<u8, <T as Config>::MaxBioLen>;
+
+	// Struct for holding the name and bio of a kitty, along with the account which has the
+	// deposit for them reserved.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct KittyMetadata<T: Config> {
+		pub name: KittyName<T>,
+		pub bio: Option<KittyBio<T>>,
+		pub depositor: T::AccountId,
+		pub deposit: BalanceOf<T>,
+	}
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -66,15 +205,107 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		/// The Currency handler for the kitties pallet.
-		type Currency: Currency<Self::AccountId>;
+		/// The Currency handler for the kitties pallet. Bids of auctions and the deposits of
+		/// kitties are reserved with it.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Identifier for the `T::Nfts` collection the kitties are minted into.
+		type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier for the item of a kitty inside the kitties collection.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
+
+		/// The NFTs pallet holding the kitty items. Ownership, transfers and transfer locks of
+		/// kitties all go through it, so kitties work with any tool built for its items. Items
+		/// are only locked while their kitty is listed or auctioned.
+		type Nfts: nonfungibles_v2::Inspect<
+				Self::AccountId,
+				CollectionId = Self::CollectionId,
+				ItemId = Self::ItemId,
+			> + nonfungibles_v2::InspectEnumerable<Self::AccountId>
+			+ nonfungibles_v2::Create<Self::AccountId, CollectionConfigFor<Self>>
+			+ nonfungibles_v2::Mutate<Self::AccountId, ItemConfig>
+			+ nonfungibles_v2::Transfer<Self::AccountId>;
+
+		/// The kitties pallet id, used to derive the account owning the kitties collection.
+		/// This account pays the collection deposit of `T::Nfts` when the first kitty is minted.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 
-		/// [2-data-structure]: The maximum amount of kitties a single account can own.
+		/// [2-data-structure]: The amount of kitties above which an account can no longer mint or
+		/// breed new ones. It is a soft limit: kitties can still be bought or received beyond it.
 		#[pallet::constant]
 		type MaxKittiesOwned: Get<u32>;
 
-		/// [4-onchain-randomness]: The type of Randomness we want to specify for this pallet.
-		type KittyRandomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+		/// The number of blocks a kitty has to wait after its first breeding.
+		#[pallet::constant]
+		type BaseBreedingCooldown: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of times the breeding cooldown of a kitty can double.
+		#[pallet::constant]
+		type MaxCooldownIndex: Get<u32>;
+
+		/// The share of the price of every sale which goes to `TreasuryAccount`. Set it to zero to
+		/// pay the whole price to the seller.
+		#[pallet::constant]
+		type ProtocolFee: Get<Perbill>;
+
+		/// The account receiving the protocol fee of sales.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The longest an auction can run for, in blocks.
+		#[pallet::constant]
+		type MaxAuctionDuration: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of auctions which can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
+		/// The number of blocks between `commit_mint` and the block whose hash seeds the DNA of
+		/// the revealed kitty. The kitty can be revealed once that block is sealed.
+		#[pallet::constant]
+		type MintRevealDelay: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks after which a mint commitment which was not revealed is
+		/// dropped. It has to leave at least one block to reveal in after `MintRevealDelay`, and
+		/// stay below the number of block hashes kept by `frame_system`.
+		#[pallet::constant]
+		type MintCommitmentExpiry: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of mint commitments which can be made in the same block.
+		#[pallet::constant]
+		type MaxMintCommitmentsPerBlock: Get<u32>;
+
+		/// The maximum number of kitties a single mint commitment can mint. It bounds the weight
+		/// of `reveal_mint`, which has to fit in a block.
+		#[pallet::constant]
+		type MaxMintPerCommit: Get<u32>;
+
+		/// The deposit reserved for every mint commitment. It is released when the commitment
+		/// is revealed, and forfeited if the commitment expires instead.
+		#[pallet::constant]
+		type MintCommitmentDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from the owner of a kitty for the storage the kitty takes. It
+		/// stays with its depositor when the kitty is transferred, moves to the buyer when the
+		/// kitty is sold, and is released when the kitty is burned.
+		#[pallet::constant]
+		type KittyDeposit: Get<BalanceOf<Self>>;
+
+		/// The longest name a kitty can be given, in bytes.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// The longest bio a kitty can be given, in bytes.
+		#[pallet::constant]
+		type MaxBioLen: Get<u32>;
+
+		/// The deposit reserved for every byte of the name and bio of a kitty, from the account
+		/// which set them. It is released when the name is replaced or cleared, or the kitty is
+		/// burned.
+		#[pallet::constant]
+		type NameDepositPerByte: Get<BalanceOf<Self>>;
 	}
 
 	/// [2-data-structure]: Keeps track of the number of kitties in existence. (hint: using StorageValue)
@@ -82,27 +313,144 @@ pub mod pallet {
 	#[pallet::getter(fn all_kitties_count)]
 	pub(super) type AllKittiesCount<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-	/// [2-data-structure]: Keep track of kitties owned by the owner account
+	/// [2-data-structure]: Maps the kitty struct to the kitty DNA. (hint: using StorageMap)
+	#[pallet::storage]
+	#[pallet::getter(fn kitties)]
+	pub type Kitties<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Kitty<T>>;
+
+	/// The kitties listed for sale, so that listings can be paged through without walking over
+	/// every kitty. A kitty is listed here exactly while it has a price.
+	#[pallet::storage]
+	pub type ForSale<T: Config> = StorageMap<_, Twox64Concat, T::Hash, ()>;
+
+	/// The `T::Nfts` collection the kitties are minted into. It is created with the first kitty.
+	#[pallet::storage]
+	#[pallet::getter(fn kitties_collection)]
+	pub type KittiesCollection<T: Config> = StorageValue<_, T::CollectionId>;
+
+	/// The item id the next kitty will be minted with.
+	#[pallet::storage]
+	pub(super) type NextItemId<T: Config> = StorageValue<_, T::ItemId, ValueQuery>;
+
+	/// Maps the kitty DNA to its item in the kitties collection.
+	#[pallet::storage]
+	#[pallet::getter(fn item_of_kitty)]
+	pub type ItemOfKitty<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::ItemId>;
+
+	/// Maps an item of the kitties collection back to the kitty DNA.
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_of_item)]
+	pub type KittyOfItem<T: Config> = StorageMap<_, Twox64Concat, T::ItemId, T::Hash>;
+
+	/// The account which has the deposit of a kitty reserved, and the amount of the deposit.
+	/// Kitties minted before deposits were introduced have none.
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_deposit)]
+	pub type KittyDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, (T::AccountId, BalanceOf<T>)>;
+
+	/// The number of kitties having each value of each gene, as decoded with the table of
+	/// `traits::TRAITS_VERSION`. Values no kitty has are not stored.
+	#[pallet::storage]
+	#[pallet::getter(fn trait_count)]
+	pub type TraitCounts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, traits::Gene, Twox64Concat, u8, u64, ValueQuery>;
+
+	/// The name and bio of the named kitties.
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_metadata)]
+	pub type KittyMetadataOf<T: Config> = StorageMap<_, Twox64Concat, T::Hash, KittyMetadata<T>>;
+
+	/// Maps the name of a kitty back to its DNA, so that no two kitties share a name.
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_named)]
+	pub type NameOf<T: Config> = StorageMap<_, Blake2_128Concat, KittyName<T>, T::Hash>;
+
+	/// Keeps track of the children of every kitty, so that a family tree can be walked down
+	/// from any kitty. The parents of a kitty are stored in the `Kitty` struct itself.
+	#[pallet::storage]
+	pub type KittyChildren<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::Hash, (), OptionQuery>;
+
+	/// Male kitties offered for breeding, mapped to the account which made the offer and the
+	/// fee other accounts have to pay to breed with them. An offer only holds while the account
+	/// which made it still owns the kitty.
+	#[pallet::storage]
+	#[pallet::getter(fn sire_fee)]
+	pub type SireFees<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, (T::AccountId, BalanceOf<T>)>;
+
+	/// The running auctions, by kitty DNA.
 	#[pallet::storage]
-	#[pallet::getter(fn kitties_owned)]
-	pub(super) type KittiesOwned<T: Config> = StorageMap<
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Auction<T>>;
+
+	/// The auctions ending at each block, which are settled by `on_initialize` at that block.
+	#[pallet::storage]
+	pub type AuctionsEndingAt<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
-		T::AccountId,
-		BoundedVec<T::Hash, T::MaxKittiesOwned>,
+		BlockNumberFor<T>,
+		BoundedVec<T::Hash, T::MaxAuctionsPerBlock>,
 		ValueQuery,
 	>;
 
-	/// [2-data-structure]: Maps the kitty struct to the kitty DNA. (hint: using StorageMap)
+	/// Standing offers to buy a kitty, from the buyer who made them. Each offer holds the
+	/// reserved amount and the block number from which it can no longer be accepted.
 	#[pallet::storage]
-	#[pallet::getter(fn kitties)]
-	pub type Kitties<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Kitty<T>>;
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		Blake2_128Concat,
+		T::AccountId,
+		(BalanceOf<T>, BlockNumberFor<T>),
+	>;
+
+	/// Pending two-phase mints, from the account which made the commitment. Every kitty is
+	/// minted through one, whether it is a new kitty or the child of two parents.
+	#[pallet::storage]
+	#[pallet::getter(fn mint_commitments)]
+	pub type MintCommitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, MintCommitment<T>>;
 
-	/// [2-data-structure]: Track the kitties owned by each account. (hint: using StorageMap)
+	/// The mint commitments expiring at each block, which are dropped by `on_initialize` at that
+	/// block along with their deposit.
 	#[pallet::storage]
-	#[pallet::getter(fn owner_of)]
-	pub(super) type KittyOwner<T: Config> =
-		StorageMap<_, Twox64Concat, T::Hash, Option<T::AccountId>, ValueQuery>;
+	pub type MintCommitmentsExpiringAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountId, T::MaxMintCommitmentsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Kitties to mint at genesis, as `(owner, dna, gender, price)`. The gender given here
+	/// overrides the one the DNA would give.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub kitties: Vec<(T::AccountId, T::Hash, Gender, Option<BalanceOf<T>>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { kitties: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, dna, gender, price) in &self.kitties {
+				let mut kitty = Kitty::<T>::new(*dna, None, 0);
+				kitty.gender = *gender;
+				kitty.price = *price;
+				Pallet::<T>::insert_kitty(owner, kitty).expect("genesis kitties are valid; qed");
+			}
+		}
+	}
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
@@ -110,22 +458,128 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		// A new kitty was successfully created.
-		Created { kitty: T::Hash, owner: T::AccountId },
+		Created {
+			kitty: T::Hash,
+			owner: T::AccountId,
+		},
 
 		// A kitty was successfully transferred.
-		Transferred { from: T::AccountId, to: T::AccountId, kitty: T::Hash },
+		Transferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			kitty: T::Hash,
+		},
 
 		// The price of a kitty was successfully set.
-		PriceSet { kitty: T::Hash, price: Option<BalanceOf<T>> },
+		PriceSet {
+			kitty: T::Hash,
+			price: Option<BalanceOf<T>>,
+		},
+
+		// A kitty was successfully sold. `price` is what the buyer paid, `fee` is the part of it
+		// which went to the treasury.
+		Sold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			kitty: T::Hash,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+
+		// A new kitty was successfully bred from two parents.
+		Bred {
+			parents: (T::Hash, T::Hash),
+			child: T::Hash,
+			owner: T::AccountId,
+		},
+
+		// The breeding fee of a sire was successfully set.
+		SireOffered {
+			kitty: T::Hash,
+			fee: Option<BalanceOf<T>>,
+		},
+
+		// A kitty was put up for auction.
+		AuctionCreated {
+			kitty: T::Hash,
+			seller: T::AccountId,
+			kind: AuctionKind,
+			start_price: BalanceOf<T>,
+			reserve: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+
+		// A bid was placed on an English auction.
+		BidPlaced {
+			kitty: T::Hash,
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+
+		// An auction was settled. `winner` is `None` if the kitty stayed with its seller.
+		AuctionSettled {
+			kitty: T::Hash,
+			winner: Option<T::AccountId>,
+		},
+
+		// An offer to buy a kitty was made, or replaced.
+		OfferMade {
+			kitty: T::Hash,
+			buyer: T::AccountId,
+			amount: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+
+		// An offer to buy a kitty was withdrawn by the buyer.
+		OfferWithdrawn {
+			kitty: T::Hash,
+			buyer: T::AccountId,
+		},
+
+		// A mint commitment was made. The kitty can be revealed after block `reveal_after`.
+		MintCommitted {
+			who: T::AccountId,
+			commitment: T::Hash,
+			reveal_after: BlockNumberFor<T>,
+		},
+
+		// A mint commitment expired before it was revealed, and its deposit was forfeited.
+		MintCommitmentExpired {
+			who: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+
+		// A kitty was released and burned. `owner` is the account which released it, which owned
+		// it last unless its item was burned in `T::Nfts` before.
+		Released {
+			kitty: T::Hash,
+			owner: T::AccountId,
+		},
 
-		// A kitty was successfully sold.
-		Sold { seller: T::AccountId, buyer: T::AccountId, kitty: T::Hash, price: BalanceOf<T> },
+		// A kitty was given a name, or a new one.
+		KittyNamed {
+			kitty: T::Hash,
+			owner: T::AccountId,
+			name: KittyName<T>,
+		},
+
+		// The name of a kitty was cleared by the root origin.
+		NameCleared {
+			kitty: T::Hash,
+		},
+
+		// A child was not born when its commitment was revealed, as one of its parents was
+		// released in the meantime. The deposit of the commitment was refunded.
+		BreedingCancelled {
+			parents: (T::Hash, T::Hash),
+			owner: T::AccountId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// An account may only own `MaxKittiesOwned` kitties.
+		/// An account owning `MaxKittiesOwned` kitties cannot mint or breed more.
 		TooManyOwned,
 		/// This kitty already exists!
 		DuplicateKitty,
@@ -141,46 +595,104 @@ pub mod pallet {
 		BidPriceTooLow,
 		/// This kitty is not for sale.
 		NotForSale,
+		/// Breeding requires one Male and one Female kitty.
+		SameGender,
+		/// This kitty has bred recently and is not ready to breed again.
+		KittyOnCooldown,
+		/// This kitty does not have the gender required for this action.
+		WrongGender,
+		/// This kitty is not offered as a sire.
+		NotOfferedAsSire,
+		/// This kitty is already on sale at a fixed price.
+		KittyOnSale,
+		/// This kitty is being auctioned.
+		KittyInAuction,
+		/// This kitty is not being auctioned.
+		NoAuction,
+		/// The duration of the auction is zero or longer than `MaxAuctionDuration`.
+		InvalidDuration,
+		/// The start price of a Dutch auction is below its reserve.
+		InvalidAuctionPrice,
+		/// Too many auctions end in the same block.
+		TooManyAuctions,
+		/// The auction has ended.
+		AuctionEnded,
+		/// The auction has not ended yet.
+		AuctionNotEnded,
+		/// There is no offer from this buyer on this kitty.
+		NoOffer,
+		/// The offer has expired.
+		OfferExpired,
+		/// This account already has a pending mint commitment.
+		AlreadyCommitted,
+		/// Too many mint commitments were made in this block.
+		TooManyMintCommitments,
+		/// This account has no pending mint commitment.
+		NoMintCommitment,
+		/// The block seeding the DNA of the kitty has not been sealed yet.
+		RevealTooEarly,
+		/// The secret does not match the commitment.
+		InvalidReveal,
+		/// Kitty names cannot be empty.
+		EmptyName,
+		/// This name is already given to another kitty.
+		NameTaken,
+		/// This kitty has no name.
+		NoName,
+		/// The number of kitties to mint is zero or above `MaxMintPerCommit`.
+		InvalidMintCount,
 	}
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Create a new unique kitty.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::create_kitty())]
-		pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			let kitty_dna = Pallet::<T>::gen_dna(&sender);
-			ensure!(!<Kitties<T>>::contains_key(kitty_dna), Error::<T>::DuplicateKitty);
-
-			// 1. map the new DNA with the struct data of Kitty
-			<Kitties<T>>::insert(kitty_dna, Kitty::<T>::new(kitty_dna, sender.clone()));
-
-			// 2. map the new DNA with its new owner
-			ensure!(!<KittyOwner<T>>::contains_key(kitty_dna), Error::<T>::DuplicateKitty);
-			<KittyOwner<T>>::insert(kitty_dna, Some(&sender));
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// Settle the auctions ending in this block and drop the mint commitments expiring in it,
+		// whose deposits are forfeited.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let ending = AuctionsEndingAt::<T>::take(now);
+			for kitty_dna in ending.iter() {
+				let _ = Self::do_settle(kitty_dna);
+			}
 
-			// 3. update the total count of kitties
-			let new_all_kitties_count =
-				Self::all_kitties_count().checked_add(1).ok_or(Error::<T>::Overflow).unwrap();
-			<AllKittiesCount<T>>::put(new_all_kitties_count);
+			let expiring = MintCommitmentsExpiringAt::<T>::take(now);
+			for who in expiring.iter() {
+				if let Some(MintCommitment { deposit, .. }) = MintCommitments::<T>::take(who) {
+					let (_, unslashed) = T::Currency::slash_reserved(who, deposit);
+					let deposit = deposit.saturating_sub(unslashed);
+					Self::deposit_event(Event::MintCommitmentExpired { who: who.clone(), deposit });
+				}
+			}
 
-			// 4. push the new kitty DNA to the list of existing kitties owned by a sender
-			KittiesOwned::<T>::try_append(&sender, kitty_dna)
-				.map_err(|_| Error::<T>::TooManyOwned)?;
+			T::WeightInfo::on_initialize(ending.len() as u32, expiring.len() as u32)
+		}
 
-			// deposit a new event when the kitty is created
-			Self::deposit_event(Event::Created { kitty: kitty_dna, owner: sender });
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
 
-			Ok(())
+		fn integrity_test() {
+			assert!(
+				T::MintCommitmentExpiry::get() >
+					T::MintRevealDelay::get().saturating_add(One::one()),
+				"mint commitments must not expire before they can be revealed"
+			);
+			assert!(
+				T::WeightInfo::reveal_mint(T::MaxMintPerCommit::get())
+					.all_lte(T::BlockWeights::get().max_block),
+				"revealing the largest mint commitment must fit in a block"
+			);
 		}
+	}
 
-		/// Directly transfer a kitty to another recipient.
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Directly transfer a kitty to another recipient. The deposit of the kitty stays
+		/// reserved from its depositor until the kitty is sold or burned.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(T::WeightInfo::transfer(T::MaxKittiesOwned::get()))]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -210,20 +722,36 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 			// 1. check if the kitty exists and is called by the kitty owner
 			let mut kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
-			ensure!(kitty.owner == sender, Error::<T>::NotOwner);
+			let (collection, item) = Self::collection_item(&kitty_dna)?;
+			ensure!(T::Nfts::owner(&collection, &item) == Some(sender), Error::<T>::NotOwner);
+			ensure!(!Auctions::<T>::contains_key(&kitty_dna), Error::<T>::KittyInAuction);
+
+			// 2. lock the item of a listed kitty, so that it cannot be moved through `T::Nfts`
+			// behind the back of a buyer
+			match (kitty.price, new_price) {
+				(None, Some(_)) => T::Nfts::disable_transfer(&collection, &item)?,
+				(Some(_), None) => T::Nfts::enable_transfer(&collection, &item)?,
+				_ => (),
+			}
 
-			// 2. set the price in storage
+			// 3. set the price in storage, and list or unlist the kitty
 			kitty.price = new_price;
 			Kitties::<T>::insert(&kitty_dna, kitty);
+			if new_price.is_some() {
+				ForSale::<T>::insert(&kitty_dna, ());
+			} else {
+				ForSale::<T>::remove(&kitty_dna);
+			}
 
-			// 3. deposit a "PriceSet" event.
+			// 4. deposit a "PriceSet" event.
 			Self::deposit_event(Event::PriceSet { kitty: kitty_dna, price: new_price });
 
 			Ok(())
 		}
 
 		/// Buy a saleable kitty. The bid price provided from the buyer has to be equal or higher
-		/// than the ask price from the seller.
+		/// than the ask price from the seller, and works as the most the buyer is willing to pay:
+		/// only the ask price is charged. A `ProtocolFee` share of it goes to the treasury.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::buy_kitty())]
 		pub fn buy_kitty(
@@ -231,77 +759,1058 @@ pub mod pallet {
 			kitty_dna: T::Hash,
 			bid_price: BalanceOf<T>,
 		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
 			let mut kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
-			let (to, from) = (ensure_signed(origin)?, kitty.clone().owner);
+			let seller = Self::owner_of(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
 
-			if let Some(price) = kitty.price {
-				ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
-				// Transfer the amount from buyer to seller
-				T::Currency::transfer(
-					&to,
-					&from,
-					price,
-					frame_support::traits::ExistenceRequirement::KeepAlive,
-				)?;
+			// 1. check the listing, then sell the kitty for the ask price
+			let price = kitty.price.ok_or(Error::<T>::NotForSale)?;
+			ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
+			Pallet::<T>::do_sell(&mut kitty, seller, buyer, price)?;
 
-				// Transfer kitty
-				Pallet::<T>::do_transfer(&mut kitty, from.clone(), to.clone())?;
+			Ok(())
+		}
 
-				// Deposit sold event
-				Self::deposit_event(Event::Sold {
-					seller: from,
-					buyer: to,
-					kitty: kitty_dna,
-					price,
-				});
-			} else {
-				return Err(Error::<T>::NotForSale.into());
+		/// Breed a new kitty from one Male and one Female kitty owned by the caller. The DNA of
+		/// the child is a random crossover of the DNA of both parents, and the child is born
+		/// once `commitment` is revealed with `reveal_mint`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::breed_kitty())]
+		pub fn breed_kitty(
+			origin: OriginFor<T>,
+			parent_1: T::Hash,
+			parent_2: T::Hash,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			// 1. check that both parents exist and are owned by the caller
+			let kitty_1 = Self::kitties(&parent_1).ok_or(Error::<T>::NoKitty)?;
+			let kitty_2 = Self::kitties(&parent_2).ok_or(Error::<T>::NoKitty)?;
+			ensure!(
+				Self::owner_of(&parent_1) == Some(sender.clone()) &&
+					Self::owner_of(&parent_2) == Some(sender.clone()),
+				Error::<T>::NotOwner
+			);
+
+			// 2. commit to the child
+			Pallet::<T>::do_breed(&sender, kitty_1, kitty_2, commitment)?;
+
+			Ok(())
+		}
+
+		/// Offer a Male kitty for breeding with the kitties of other accounts, for the given
+		/// fee. Passing `None` as the fee withdraws the offer.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::offer_sire())]
+		pub fn offer_sire(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			fee: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			// 1. check if the kitty exists, is a Male and is called by the kitty owner
+			let kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			ensure!(Self::owner_of(&kitty_dna) == Some(sender.clone()), Error::<T>::NotOwner);
+			ensure!(kitty.gender == Gender::Male, Error::<T>::WrongGender);
+
+			// 2. set the breeding fee in storage
+			match fee {
+				Some(fee) => SireFees::<T>::insert(&kitty_dna, (sender, fee)),
+				None => SireFees::<T>::remove(&kitty_dna),
 			}
 
+			// 3. deposit a "SireOffered" event.
+			Self::deposit_event(Event::SireOffered { kitty: kitty_dna, fee });
+
+			Ok(())
+		}
+
+		/// Breed a Female kitty owned by the caller with a sire offered by another account. The
+		/// caller pays the breeding fee to the owner of the sire and receives the child once
+		/// `commitment` is revealed with `reveal_mint`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::breed_with_sire())]
+		pub fn breed_with_sire(
+			origin: OriginFor<T>,
+			own_female: T::Hash,
+			sire: T::Hash,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			// 1. check that the caller owns the female and that the sire is on offer
+			let dam = Self::kitties(&own_female).ok_or(Error::<T>::NoKitty)?;
+			ensure!(Self::owner_of(&own_female) == Some(sender.clone()), Error::<T>::NotOwner);
+			ensure!(dam.gender == Gender::Female, Error::<T>::WrongGender);
+			let sire_kitty = Self::kitties(&sire).ok_or(Error::<T>::NoKitty)?;
+			let (sire_owner, fee) = Self::sire_fee(&sire).ok_or(Error::<T>::NotOfferedAsSire)?;
+			ensure!(
+				Self::owner_of(&sire) == Some(sire_owner.clone()),
+				Error::<T>::NotOfferedAsSire
+			);
+			ensure!(sire_owner != sender, Error::<T>::TransferToSelf);
+
+			// 2. pay the breeding fee to the owner of the sire
+			T::Currency::transfer(&sender, &sire_owner, fee, ExistenceRequirement::KeepAlive)?;
+
+			// 3. commit to the child, which goes to the owner of the female
+			Pallet::<T>::do_breed(&sender, dam, sire_kitty, commitment)?;
+
+			Ok(())
+		}
+
+		/// Put a kitty up for auction for `duration` blocks.
+		///
+		/// An English auction sells to the best bid at or above `start_price` once it ends,
+		/// unless that bid is below `reserve`. A Dutch auction asks for `start_price` at first
+		/// and lowers the price every block, down to `reserve` at the end. It sells to the first
+		/// bid at the current price.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			kind: AuctionKind,
+			start_price: BalanceOf<T>,
+			reserve: BalanceOf<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+
+			// 1. check that the caller owns the kitty and that it is not on sale yet
+			let kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			let (collection, item) = Self::collection_item(&kitty_dna)?;
+			ensure!(
+				T::Nfts::owner(&collection, &item) == Some(seller.clone()),
+				Error::<T>::NotOwner
+			);
+			ensure!(kitty.price.is_none(), Error::<T>::KittyOnSale);
+			ensure!(!Auctions::<T>::contains_key(&kitty_dna), Error::<T>::KittyInAuction);
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxAuctionDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			ensure!(
+				kind == AuctionKind::English || start_price >= reserve,
+				Error::<T>::InvalidAuctionPrice
+			);
+
+			// 2. schedule the settlement and lock the item until then
+			let start = <frame_system::Pallet<T>>::block_number();
+			let end = start.saturating_add(duration);
+			AuctionsEndingAt::<T>::try_append(end, kitty_dna)
+				.map_err(|_| Error::<T>::TooManyAuctions)?;
+			T::Nfts::disable_transfer(&collection, &item)?;
+
+			Auctions::<T>::insert(
+				&kitty_dna,
+				Auction {
+					seller: seller.clone(),
+					kind,
+					start_price,
+					reserve,
+					start,
+					end,
+					best_bid: None,
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated {
+				kitty: kitty_dna,
+				seller,
+				kind,
+				start_price,
+				reserve,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Bid on the auction of a kitty.
+		///
+		/// A bid on an English auction has to beat the best bid so far and is reserved until
+		/// the auction is settled or the bid is beaten. A bid on a Dutch auction buys the kitty
+		/// right away at the current price, if `amount` covers it.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+
+			let mut auction = Self::auctions(&kitty_dna).ok_or(Error::<T>::NoAuction)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now < auction.end, Error::<T>::AuctionEnded);
+			ensure!(auction.seller != bidder, Error::<T>::TransferToSelf);
+
+			match auction.kind {
+				AuctionKind::English => {
+					// 1. the bid has to beat the best bid so far
+					ensure!(amount >= auction.start_price, Error::<T>::BidPriceTooLow);
+					if let Some((_, best)) = &auction.best_bid {
+						ensure!(amount > *best, Error::<T>::BidPriceTooLow);
+					}
+
+					// 2. reserve the new bid and release the one it beats
+					T::Currency::reserve(&bidder, amount)?;
+					if let Some((previous, best)) = auction.best_bid.take() {
+						T::Currency::unreserve(&previous, best);
+					}
+					auction.best_bid = Some((bidder.clone(), amount));
+					Auctions::<T>::insert(&kitty_dna, auction);
+
+					Self::deposit_event(Event::BidPlaced { kitty: kitty_dna, bidder, amount });
+				},
+				AuctionKind::Dutch => {
+					// 1. the bid has to cover the current price, which is all that is charged
+					let price = auction.dutch_price(now);
+					ensure!(amount >= price, Error::<T>::BidPriceTooLow);
+
+					// 2. close the auction and sell the kitty
+					let mut kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
+					Self::close_auction(&kitty_dna)?;
+					Self::do_sell(&mut kitty, auction.seller, bidder.clone(), price)?;
+
+					Self::deposit_event(Event::AuctionSettled {
+						kitty: kitty_dna,
+						winner: Some(bidder),
+					});
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Settle an auction which has ended. Ended auctions are settled automatically at the
+		/// start of their last block, so this is only needed if that did not happen.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, kitty_dna: T::Hash) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let auction = Self::auctions(&kitty_dna).ok_or(Error::<T>::NoAuction)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now >= auction.end, Error::<T>::AuctionNotEnded);
+
+			Self::do_settle(&kitty_dna)
+		}
+
+		/// Offer to buy a kitty, whether it is listed or not, for `amount`. The amount is
+		/// reserved until the offer is accepted or withdrawn, and the owner can accept the offer
+		/// before block `expires_at`. A new offer from the same buyer replaces the previous one.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			amount: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			// 1. check that the kitty exists, is owned by someone else and that the offer is
+			// still open at the next block
+			let owner = Self::owner_of(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			ensure!(owner != buyer, Error::<T>::TransferToSelf);
+			ensure!(
+				expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::OfferExpired
+			);
+
+			// 2. release the offer being replaced and reserve the new one
+			if let Some((previous, _)) = Offers::<T>::get(&kitty_dna, &buyer) {
+				T::Currency::unreserve(&buyer, previous);
+			}
+			T::Currency::reserve(&buyer, amount)?;
+			Offers::<T>::insert(&kitty_dna, &buyer, (amount, expires_at));
+
+			Self::deposit_event(Event::OfferMade { kitty: kitty_dna, buyer, amount, expires_at });
+
+			Ok(())
+		}
+
+		/// Accept the offer of `buyer` on a kitty owned by the caller, selling the kitty for the
+		/// amount of the offer.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+
+			// 1. check that the caller owns the kitty and that the offer is still open
+			let mut kitty = Self::kitties(&kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			ensure!(Self::owner_of(&kitty_dna) == Some(seller.clone()), Error::<T>::NotOwner);
+			let (amount, expires_at) =
+				Offers::<T>::take(&kitty_dna, &buyer).ok_or(Error::<T>::NoOffer)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < expires_at,
+				Error::<T>::OfferExpired
+			);
+
+			// 2. release the offer and sell the kitty with it
+			T::Currency::unreserve(&buyer, amount);
+			Pallet::<T>::do_sell(&mut kitty, seller, buyer, amount)?;
+
+			Ok(())
+		}
+
+		/// Withdraw the offer of the caller on a kitty, releasing the offered amount.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, kitty_dna: T::Hash) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let (amount, _) = Offers::<T>::take(&kitty_dna, &buyer).ok_or(Error::<T>::NoOffer)?;
+			T::Currency::unreserve(&buyer, amount);
+
+			Self::deposit_event(Event::OfferWithdrawn { kitty: kitty_dna, buyer });
+
+			Ok(())
+		}
+
+		/// Commit to minting a new kitty. Every kitty is minted in two phases, so that its DNA
+		/// cannot be steered by block authors. `commitment` is the hash of the caller account
+		/// and a secret, which is later passed to `reveal_mint`. A `MintCommitmentDeposit` is
+		/// reserved until then.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::commit_mint())]
+		pub fn commit_mint(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::below_max_owned(&who, 1), Error::<T>::TooManyOwned);
+			Self::do_commit(who, commitment, PendingMint::Kitties(1))
+		}
+
+		/// Reveal the secret of the mint commitment of the caller, minting the kitties it was
+		/// made for and releasing its deposit. Their DNA is derived from the secret and the hash
+		/// of the block `MintRevealDelay` blocks after the commitment. Neither the caller nor
+		/// the block authors know both when the commitment is made.
+		///
+		/// A child is not born if either of its parents was released since the commitment, and
+		/// the deposit of the commitment is refunded all the same.
+		///
+		/// The weight of minting `MaxMintPerCommit` kitties is charged upfront, and refunded
+		/// down to the number of kitties the commitment was made for.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::reveal_mint(T::MaxMintPerCommit::get()))]
+		pub fn reveal_mint(origin: OriginFor<T>, secret: T::Hash) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// 1. check the secret against a commitment whose seed block is sealed
+			let MintCommitment { commitment, committed_at, deposit, mint } =
+				MintCommitments::<T>::take(&who).ok_or(Error::<T>::NoMintCommitment)?;
+			let seed_block = committed_at.saturating_add(T::MintRevealDelay::get());
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() > seed_block,
+				Error::<T>::RevealTooEarly
+			);
+			ensure!(T::Hashing::hash_of(&(&who, &secret)) == commitment, Error::<T>::InvalidReveal);
+			MintCommitmentsExpiringAt::<T>::mutate(
+				committed_at.saturating_add(T::MintCommitmentExpiry::get()),
+				|expiring| expiring.retain(|account| *account != who),
+			);
+			T::Currency::unreserve(&who, deposit);
+
+			// 2. mint the kitties
+			let seed = Self::reveal_seed(&who, &secret, seed_block);
+			let minted = match mint {
+				PendingMint::Kitties(count) => {
+					for index in 0..count {
+						let kitty_dna =
+							Self::mint(&who, T::Hashing::hash_of(&(seed, index)), None, 0)?;
+						Self::deposit_event(Event::Created {
+							kitty: kitty_dna,
+							owner: who.clone(),
+						});
+					}
+					count
+				},
+				PendingMint::Child { parents, generation } => {
+					// either parent may have been released since the commitment
+					if !Kitties::<T>::contains_key(&parents.0) ||
+						!Kitties::<T>::contains_key(&parents.1)
+					{
+						Self::deposit_event(Event::BreedingCancelled { parents, owner: who });
+						return Ok(Some(T::WeightInfo::reveal_mint(0)).into())
+					}
+					let child_dna = Self::breed_dna(&parents.0, &parents.1, &seed);
+					let child = Self::mint(&who, child_dna, Some(parents), generation)?;
+					Self::deposit_event(Event::Bred { parents, child, owner: who });
+					1
+				},
+			};
+
+			Ok(Some(T::WeightInfo::reveal_mint(minted)).into())
+		}
+
+		/// Commit to minting `count` new kitties at once, such as the starter pack of a new
+		/// player. They are all minted by the same `reveal_mint`, so `count` is at most
+		/// `MaxMintPerCommit`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::commit_mint())]
+		pub fn create_kitties(
+			origin: OriginFor<T>,
+			count: u32,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(count > 0 && count <= T::MaxMintPerCommit::get(), Error::<T>::InvalidMintCount);
+			ensure!(Self::below_max_owned(&who, count), Error::<T>::TooManyOwned);
+			Self::do_commit(who, commitment, PendingMint::Kitties(count))
+		}
+
+		/// Release a kitty of the caller, burning it along with its item. The deposits of the
+		/// kitty and of its item are refunded, which frees a slot under `MaxKittiesOwned`. Kitties
+		/// being auctioned cannot be released, and offers made on the kitty are left for their
+		/// buyers to withdraw.
+		///
+		/// A kitty whose item was burned in `T::Nfts` directly can be released by anyone, which
+		/// forgets the kitty and refunds its deposits.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::release_kitty())]
+		pub fn release_kitty(origin: OriginFor<T>, kitty_dna: T::Hash) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_burn(&kitty_dna, Some(&owner))?;
+
+			Self::deposit_event(Event::Released { kitty: kitty_dna, owner });
+
+			Ok(())
+		}
+
+		/// Name a kitty of the caller, with an optional bio. Names are unique across all
+		/// kitties. A deposit of `NameDepositPerByte` for every byte of the name and bio is
+		/// reserved from the caller, and the deposit of the previous name is released.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_kitty_name())]
+		pub fn set_kitty_name(
+			origin: OriginFor<T>,
+			kitty_dna: T::Hash,
+			name: KittyName<T>,
+			bio: Option<KittyBio<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(Kitties::<T>::contains_key(&kitty_dna), Error::<T>::NoKitty);
+			ensure!(Self::owner_of(&kitty_dna).as_ref() == Some(&owner), Error::<T>::NotOwner);
+			ensure!(!name.is_empty(), Error::<T>::EmptyName);
+			if let Some(named) = Self::kitty_named(&name) {
+				ensure!(named == kitty_dna, Error::<T>::NameTaken);
+			}
+
+			// 1. reserve the deposit of the new name, releasing the one of the previous name
+			let bytes = name.len().saturating_add(bio.as_ref().map_or(0, |bio| bio.len()));
+			let deposit = T::NameDepositPerByte::get().saturating_mul((bytes as u32).into());
+			let previous = KittyMetadataOf::<T>::get(&kitty_dna);
+			match &previous {
+				Some(previous) if previous.depositor == owner =>
+					if deposit > previous.deposit {
+						T::Currency::reserve(&owner, deposit - previous.deposit)?;
+					} else {
+						T::Currency::unreserve(&owner, previous.deposit - deposit);
+					},
+				Some(previous) => {
+					T::Currency::reserve(&owner, deposit)?;
+					T::Currency::unreserve(&previous.depositor, previous.deposit);
+				},
+				None => T::Currency::reserve(&owner, deposit)?,
+			}
+
+			// 2. point the new name at the kitty, freeing the previous name
+			if let Some(previous) = previous {
+				NameOf::<T>::remove(&previous.name);
+			}
+			NameOf::<T>::insert(&name, kitty_dna);
+			KittyMetadataOf::<T>::insert(
+				&kitty_dna,
+				KittyMetadata { name: name.clone(), bio, depositor: owner.clone(), deposit },
+			);
+
+			Self::deposit_event(Event::KittyNamed { kitty: kitty_dna, owner, name });
+
+			Ok(())
+		}
+
+		/// Clear the name and bio of a kitty, releasing their deposit, for moderation. Can only
+		/// be called by the root origin.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::force_clear_name())]
+		pub fn force_clear_name(origin: OriginFor<T>, kitty_dna: T::Hash) -> DispatchResult {
+			ensure_root(origin)?;
+			let metadata = KittyMetadataOf::<T>::take(&kitty_dna).ok_or(Error::<T>::NoName)?;
+			Self::release_name(metadata);
+
+			Self::deposit_event(Event::NameCleared { kitty: kitty_dna });
+
 			Ok(())
 		}
 	}
 
 	// Pallet's internal functions.
 	impl<T: Config> Pallet<T> {
-		// [4-onchain-randomness] Generates and returns DNA and Gender
-		fn gen_dna(minter: &T::AccountId) -> T::Hash {
-			let (output, block_number) = T::KittyRandomness::random(&b"dna"[..]);
-			let payload = (output, block_number, minter);
-			T::Hashing::hash_of(&payload)
+		// Derives the seed of a revealed mint from the secret and the hash of the block seeding
+		// it, which was still in the future when the secret was committed.
+		fn reveal_seed(
+			minter: &T::AccountId,
+			secret: &T::Hash,
+			seed_block: BlockNumberFor<T>,
+		) -> T::Hash {
+			let seed = <frame_system::Pallet<T>>::block_hash(seed_block);
+			T::Hashing::hash_of(&(b"reveal", seed, secret, minter))
+		}
+
+		// Mixes the DNA of two parents. Every bit of the child DNA is picked from one of the
+		// parents, using a selector derived from the seed of the reveal so that siblings do not
+		// share the same DNA.
+		fn breed_dna(parent_1: &T::Hash, parent_2: &T::Hash, seed: &T::Hash) -> T::Hash {
+			let selector = T::Hashing::hash_of(&(b"breed", seed));
+			let mut child = T::Hash::default();
+			for (i, byte) in child.as_mut().iter_mut().enumerate() {
+				let select = selector.as_ref()[i];
+				*byte = (select & parent_1.as_ref()[i]) | (!select & parent_2.as_ref()[i]);
+			}
+			child
+		}
+
+		// Stores a new kitty with the given DNA and mints its item to `owner`. Bred kitties are
+		// also registered as a child of both of their parents.
+		pub(crate) fn mint(
+			owner: &T::AccountId,
+			kitty_dna: T::Hash,
+			parents: Option<(T::Hash, T::Hash)>,
+			generation: u32,
+		) -> Result<T::Hash, DispatchError> {
+			Self::insert_kitty(owner, Kitty::<T>::new(kitty_dna, parents, generation))?;
+
+			// link the new kitty to its parents
+			if let Some((parent_1, parent_2)) = parents {
+				<KittyChildren<T>>::insert(parent_1, kitty_dna, ());
+				<KittyChildren<T>>::insert(parent_2, kitty_dna, ());
+			}
+
+			Ok(kitty_dna)
+		}
+
+		// Stores a kitty and mints its item to `owner`, counting it in `AllKittiesCount` and its
+		// traits in `TraitCounts`. The deposit of the kitty is reserved from `owner`.
+		fn insert_kitty(owner: &T::AccountId, kitty: Kitty<T>) -> DispatchResult {
+			ensure!(!<Kitties<T>>::contains_key(kitty.dna), Error::<T>::DuplicateKitty);
+
+			// 1. update the total count of kitties
+			let new_all_kitties_count =
+				Self::all_kitties_count().checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			// 2. reserve the deposit of the kitty
+			let deposit = T::KittyDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::reserve(owner, deposit)?;
+				KittyDeposits::<T>::insert(kitty.dna, (owner.clone(), deposit));
+			}
+
+			// 3. mint the kitty item to its owner
+			Self::mint_item(owner, &kitty, false)?;
+
+			// 4. map the new DNA with the struct data of Kitty
+			Self::count_traits(&kitty.dna, true);
+			<Kitties<T>>::insert(kitty.dna, kitty);
+			<AllKittiesCount<T>>::put(new_all_kitties_count);
+
+			Ok(())
+		}
+
+		// Mints the item of a kitty to `owner` and stores the DNA, gender and generation of the
+		// kitty as attributes of the item. The item deposit is paid by `owner`, or by the
+		// account of this pallet if `deposit_collection_owner` is set.
+		pub(crate) fn mint_item(
+			owner: &T::AccountId,
+			kitty: &Kitty<T>,
+			deposit_collection_owner: bool,
+		) -> DispatchResult {
+			ensure!(!ItemOfKitty::<T>::contains_key(kitty.dna), Error::<T>::DuplicateKitty);
+
+			let collection = Self::get_or_create_collection()?;
+			let item = NextItemId::<T>::get();
+			NextItemId::<T>::put(item.checked_add(&One::one()).ok_or(Error::<T>::Overflow)?);
+
+			T::Nfts::mint_into(
+				&collection,
+				&item,
+				owner,
+				&ItemConfig { settings: ItemSettings::all_enabled() },
+				deposit_collection_owner,
+			)?;
+			T::Nfts::set_typed_attribute(&collection, &item, b"dna", &kitty.dna)?;
+			T::Nfts::set_typed_attribute(&collection, &item, b"gender", &kitty.gender)?;
+			T::Nfts::set_typed_attribute(&collection, &item, b"generation", &kitty.generation)?;
+			if kitty.price.is_some() {
+				T::Nfts::disable_transfer(&collection, &item)?;
+				ForSale::<T>::insert(kitty.dna, ());
+			}
+
+			ItemOfKitty::<T>::insert(kitty.dna, item);
+			KittyOfItem::<T>::insert(item, kitty.dna);
+
+			Ok(())
+		}
+
+		/// The account of this pallet, which owns the kitties collection.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		// Returns the kitties collection, creating it on first use.
+		fn get_or_create_collection() -> Result<T::CollectionId, DispatchError> {
+			if let Some(collection) = KittiesCollection::<T>::get() {
+				return Ok(collection);
+			}
+
+			let config = CollectionConfigFor::<T> {
+				settings: CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: MintSettings::default(),
+			};
+			let account = Self::account_id();
+			let collection = T::Nfts::create_collection(&account, &account, &config)?;
+			KittiesCollection::<T>::put(collection);
+
+			Ok(collection)
+		}
+
+		/// Returns the collection and the item of a kitty.
+		pub fn collection_item(
+			kitty_dna: &T::Hash,
+		) -> Result<(T::CollectionId, T::ItemId), DispatchError> {
+			let collection = Self::kitties_collection().ok_or(Error::<T>::NoKitty)?;
+			let item = Self::item_of_kitty(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			Ok((collection, item))
+		}
+
+		/// Returns the current owner of a kitty.
+		pub fn owner_of(kitty_dna: &T::Hash) -> Option<T::AccountId> {
+			let (collection, item) = Self::collection_item(kitty_dna).ok()?;
+			T::Nfts::owner(&collection, &item)
+		}
+
+		/// Returns a kitty along with its current owner.
+		pub fn kitty_with_owner(kitty_dna: &T::Hash) -> Option<(Kitty<T>, T::AccountId)> {
+			Some((Self::kitties(kitty_dna)?, Self::owner_of(kitty_dna)?))
+		}
+
+		/// Returns the DNA of every kitty owned by `who`.
+		pub fn kitties_of(who: &T::AccountId) -> Vec<T::Hash> {
+			Self::kitties_collection().map_or_else(Vec::new, |collection| {
+				T::Nfts::owned_in_collection(&collection, who)
+					.filter_map(|item| Self::kitty_of_item(item))
+					.collect()
+			})
+		}
+
+		/// Returns the DNA of the kitties on page `page` of the kitties listed at a fixed price,
+		/// with `page_size` kitties per page.
+		pub fn kitties_for_sale(page: u32, page_size: u32) -> Vec<T::Hash> {
+			ForSale::<T>::iter_keys()
+				.skip(page.saturating_mul(page_size) as usize)
+				.take(page_size as usize)
+				.collect()
+		}
+
+		/// Returns how rare the traits of a kitty are among all the kitties in existence, if there
+		/// is a kitty with this DNA.
+		pub fn kitty_rarity(kitty_dna: &T::Hash) -> Option<traits::KittyRarity> {
+			if !Kitties::<T>::contains_key(kitty_dna) {
+				return None
+			}
+			let population = Self::all_kitties_count();
+			let trait_counts: Vec<u64> = traits::decode(kitty_dna.as_ref())
+				.traits
+				.iter()
+				.map(|kitty_trait| Self::trait_count(kitty_trait.gene, kitty_trait.value))
+				.collect();
+			let score = trait_counts
+				.iter()
+				.map(|count| population.saturating_mul(traits::RARITY_POINTS) / (*count).max(1))
+				.fold(0, u64::saturating_add);
+			Some(traits::KittyRarity { score, trait_counts, population })
+		}
+
+		/// Returns the number of kitties owned by `who`.
+		pub fn owned_count(who: &T::AccountId) -> u32 {
+			Self::kitties_collection().map_or(0, |collection| {
+				T::Nfts::owned_in_collection(&collection, who).count() as u32
+			})
+		}
+
+		// Frees the name of a kitty whose metadata was taken, and releases its deposit.
+		fn release_name(metadata: KittyMetadata<T>) {
+			NameOf::<T>::remove(&metadata.name);
+			T::Currency::unreserve(&metadata.depositor, metadata.deposit);
+		}
+
+		// Adds the traits of a minted kitty to `TraitCounts`, or takes away those of a burned one.
+		pub(crate) fn count_traits(kitty_dna: &T::Hash, minted: bool) {
+			for kitty_trait in traits::decode(kitty_dna.as_ref()).traits {
+				TraitCounts::<T>::mutate_exists(kitty_trait.gene, kitty_trait.value, |count| {
+					let counted = count.unwrap_or_default();
+					let counted =
+						if minted { counted.saturating_add(1) } else { counted.saturating_sub(1) };
+					*count = Some(counted).filter(|counted| !counted.is_zero());
+				});
+			}
+		}
+
+		// Returns whether `who` can mint or breed `count` more kitties without owning more than
+		// `MaxKittiesOwned` of them. No more than `MaxKittiesOwned` kitties of `who` are walked
+		// over, since accounts can own any number of them.
+		fn below_max_owned(who: &T::AccountId, count: u32) -> bool {
+			let max = T::MaxKittiesOwned::get();
+			let owned = Self::kitties_collection().map_or(0, |collection| {
+				T::Nfts::owned_in_collection(&collection, who).take(max as usize).count() as u32
+			});
+			owned.saturating_add(count) <= max
+		}
+
+		// helper shared method to use for breed_kitty and breed_with_sire. Both parents are put
+		// on cooldown, and the child is given to `owner` once `commitment` is revealed.
+		fn do_breed(
+			owner: &T::AccountId,
+			mut kitty_1: Kitty<T>,
+			mut kitty_2: Kitty<T>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			// 1. only a Male and a Female kitty which are both off cooldown can breed
+			ensure!(kitty_1.gender != kitty_2.gender, Error::<T>::SameGender);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				kitty_1.ready_at <= now && kitty_2.ready_at <= now,
+				Error::<T>::KittyOnCooldown
+			);
+			ensure!(Self::below_max_owned(owner, 1), Error::<T>::TooManyOwned);
+
+			// 2. commit to the child, one generation after the youngest of its parents
+			let parents = (kitty_1.dna, kitty_2.dna);
+			let generation = kitty_1.generation.max(kitty_2.generation).saturating_add(1);
+			Self::do_commit(owner.clone(), commitment, PendingMint::Child { parents, generation })?;
+
+			// 3. put both parents on cooldown
+			kitty_1.start_cooldown(now);
+			kitty_2.start_cooldown(now);
+			Kitties::<T>::insert(&parents.0, kitty_1);
+			Kitties::<T>::insert(&parents.1, kitty_2);
+
+			Ok(())
+		}
+
+		// Records a mint commitment of `who` and reserves its deposit. The commitment is
+		// dropped at `MintCommitmentExpiry` blocks from now unless it is revealed before.
+		fn do_commit(
+			who: T::AccountId,
+			commitment: T::Hash,
+			mint: PendingMint<T::Hash>,
+		) -> DispatchResult {
+			ensure!(!MintCommitments::<T>::contains_key(&who), Error::<T>::AlreadyCommitted);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			MintCommitmentsExpiringAt::<T>::try_mutate(
+				now.saturating_add(T::MintCommitmentExpiry::get()),
+				|expiring| expiring.try_push(who.clone()),
+			)
+			.map_err(|_| Error::<T>::TooManyMintCommitments)?;
+			let deposit = T::MintCommitmentDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			MintCommitments::<T>::insert(
+				&who,
+				MintCommitment { commitment, committed_at: now, deposit, mint },
+			);
+
+			Self::deposit_event(Event::MintCommitted {
+				who,
+				commitment,
+				reveal_after: now.saturating_add(T::MintRevealDelay::get()),
+			});
+
+			Ok(())
+		}
+
+		// Sells a kitty for `price`. The kitty is checked before any funds move, then the buyer
+		// takes over its deposit and pays for it. A `ProtocolFee` share of the price goes to the
+		// treasury, the rest to the seller. A failed payment reverts the whole sale, deposit
+		// included.
+		fn do_sell(
+			kitty: &mut Kitty<T>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			// 1. check that the kitty can change hands
+			ensure!(seller != buyer, Error::<T>::TransferToSelf);
+			ensure!(!Auctions::<T>::contains_key(&kitty.dna), Error::<T>::KittyInAuction);
+			ensure!(Self::owner_of(&kitty.dna).as_ref() == Some(&seller), Error::<T>::NotOwner);
+
+			// 2. the buyer takes over the deposit of the kitty
+			if let Some((depositor, deposit)) = KittyDeposits::<T>::get(&kitty.dna) {
+				T::Currency::reserve(&buyer, deposit)?;
+				T::Currency::unreserve(&depositor, deposit);
+				KittyDeposits::<T>::insert(&kitty.dna, (buyer.clone(), deposit));
+			}
+
+			// 3. pay the protocol fee to the treasury and the rest of the price to the seller
+			let fee = T::ProtocolFee::get() * price;
+			if !fee.is_zero() {
+				T::Currency::transfer(
+					&buyer,
+					&T::TreasuryAccount::get(),
+					fee,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+			T::Currency::transfer(
+				&buyer,
+				&seller,
+				price.saturating_sub(fee),
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			// 4. transfer the kitty
+			Pallet::<T>::do_transfer(kitty, seller.clone(), buyer.clone())?;
+
+			// 5. deposit a "Sold" event with the price actually paid
+			Self::deposit_event(Event::Sold { seller, buyer, kitty: kitty.dna, price, fee });
+
+			Ok(())
+		}
+
+		// Removes the auction of a kitty from `Auctions` and from the auctions ending at its
+		// last block, and unlocks its item.
+		fn close_auction(kitty_dna: &T::Hash) -> Result<Auction<T>, DispatchError> {
+			let auction = Auctions::<T>::take(kitty_dna).ok_or(Error::<T>::NoAuction)?;
+			AuctionsEndingAt::<T>::mutate(auction.end, |ending| {
+				ending.retain(|dna| dna != kitty_dna)
+			});
+			let (collection, item) = Self::collection_item(kitty_dna)?;
+			T::Nfts::enable_transfer(&collection, &item)?;
+			Ok(auction)
+		}
+
+		// Settles an auction which has ended. The best bid of an English auction buys the
+		// kitty if it meets the reserve. If it does not, or the sale fails, the bid is released
+		// and the kitty stays with the seller.
+		fn do_settle(kitty_dna: &T::Hash) -> DispatchResult {
+			let auction = Self::close_auction(kitty_dna)?;
+			let mut winner = None;
+
+			if let Some((bidder, amount)) = auction.best_bid {
+				T::Currency::unreserve(&bidder, amount);
+				if amount >= auction.reserve {
+					let sold = frame_support::storage::with_storage_layer(|| {
+						let mut kitty = Self::kitties(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+						Self::do_sell(&mut kitty, auction.seller, bidder.clone(), amount)
+					});
+					if sold.is_ok() {
+						winner = Some(bidder);
+					}
+				}
+			}
+
+			Self::deposit_event(Event::AuctionSettled { kitty: *kitty_dna, winner });
+
+			Ok(())
+		}
+
+		/// Checks the invariants tying the kitties to their items and indexes.
+		///
+		/// Transfers and sales touch several maps of this pallet and of `T::Nfts`, so a change
+		/// which is not applied in full would show up here.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), TryRuntimeError> {
+			// 1. every kitty is counted, and has an item of its own
+			let kitties = Kitties::<T>::iter_keys().count() as u64;
+			ensure!(
+				Self::all_kitties_count() == kitties,
+				"AllKittiesCount should match the number of kitties"
+			);
+			for (kitty_dna, kitty) in Kitties::<T>::iter() {
+				ensure!(kitty.dna == kitty_dna, "a kitty should be stored under its own DNA");
+				let item =
+					Self::item_of_kitty(kitty_dna).ok_or("every kitty should have an item")?;
+				ensure!(
+					Self::kitty_of_item(item) == Some(kitty_dna),
+					"the item of a kitty should map back to it"
+				);
+				// the item of an unlocked kitty can be burned in `T::Nfts` before it is released
+				ensure!(
+					Self::owner_of(&kitty_dna).is_some() ||
+						(kitty.price.is_none() && !Auctions::<T>::contains_key(kitty_dna)),
+					"every locked kitty should have an owner"
+				);
+			}
+
+			// 2. no item is shared by two kitties, so no kitty can have two owners
+			ensure!(
+				ItemOfKitty::<T>::iter_keys().count() as u64 == kitties &&
+					KittyOfItem::<T>::iter_keys().count() as u64 == kitties,
+				"kitties and their items should map one to one"
+			);
+
+			// 3. every auction is settled at its end, for the kitty of the seller
+			for (kitty_dna, auction) in Auctions::<T>::iter() {
+				ensure!(
+					AuctionsEndingAt::<T>::get(auction.end).contains(&kitty_dna),
+					"every auction should be indexed at its end"
+				);
+				ensure!(
+					Self::owner_of(&kitty_dna) == Some(auction.seller),
+					"the seller should own the auctioned kitty"
+				);
+			}
+
+			// 4. every mint commitment expires
+			for (who, MintCommitment { committed_at, .. }) in MintCommitments::<T>::iter() {
+				let expires_at = committed_at.saturating_add(T::MintCommitmentExpiry::get());
+				ensure!(
+					MintCommitmentsExpiringAt::<T>::get(expires_at).contains(&who),
+					"every mint commitment should be indexed at its expiry"
+				);
+			}
+
+			// 5. every deposit belongs to a kitty
+			for kitty_dna in KittyDeposits::<T>::iter_keys() {
+				ensure!(
+					Kitties::<T>::contains_key(kitty_dna),
+					"every deposit should belong to a kitty"
+				);
+			}
+
+			// 6. the traits of every kitty are counted, and only theirs
+			let mut trait_counts: Vec<(traits::Gene, u8, u64)> = Vec::new();
+			for kitty_dna in Kitties::<T>::iter_keys() {
+				for kitty_trait in traits::decode(kitty_dna.as_ref()).traits {
+					match trait_counts.iter_mut().find(|(gene, value, _)| {
+						*gene == kitty_trait.gene && *value == kitty_trait.value
+					}) {
+						Some((_, _, count)) => *count += 1,
+						None => trait_counts.push((kitty_trait.gene, kitty_trait.value, 1)),
+					}
+				}
+			}
+			ensure!(
+				TraitCounts::<T>::iter_keys().count() == trait_counts.len(),
+				"only the traits of existing kitties should be counted"
+			);
+			for (gene, value, count) in trait_counts {
+				ensure!(
+					Self::trait_count(gene, value) == count,
+					"TraitCounts should match the traits of the kitties"
+				);
+			}
+
+			// 7. every name belongs to a kitty, and is indexed back to it
+			for (kitty_dna, metadata) in KittyMetadataOf::<T>::iter() {
+				ensure!(
+					Kitties::<T>::contains_key(kitty_dna),
+					"every name should belong to a kitty"
+				);
+				ensure!(
+					Self::kitty_named(&metadata.name) == Some(kitty_dna),
+					"every name should be indexed back to its kitty"
+				);
+			}
+			ensure!(
+				NameOf::<T>::iter_keys().count() == KittyMetadataOf::<T>::iter_keys().count(),
+				"NameOf should only index the names of kitties"
+			);
+
+			// 8. exactly the kitties with a price are listed for sale
+			for kitty_dna in ForSale::<T>::iter_keys() {
+				ensure!(
+					Self::kitties(kitty_dna).map_or(false, |kitty| kitty.price.is_some()),
+					"every listed kitty should have a price"
+				);
+			}
+			let listed = Kitties::<T>::iter_values().filter(|kitty| kitty.price.is_some()).count();
+			ensure!(
+				ForSale::<T>::iter_keys().count() == listed,
+				"every kitty with a price should be listed"
+			);
+
+			Ok(())
 		}
 
 		// helper shared method to use for buy_kitty and transfer
-		fn do_transfer(
+		pub(crate) fn do_transfer(
 			kitty: &mut Kitty<T>,
 			from: T::AccountId,
 			to: T::AccountId,
 		) -> DispatchResult {
 			ensure!(from != to, Error::<T>::TransferToSelf);
+			ensure!(!Auctions::<T>::contains_key(&kitty.dna), Error::<T>::KittyInAuction);
 
-			ensure!(kitty.owner == from, Error::<T>::NotOwner);
+			let (collection, item) = Self::collection_item(&kitty.dna)?;
+			ensure!(T::Nfts::owner(&collection, &item) == Some(from), Error::<T>::NotOwner);
 
-			// 1. reset the price of a kitty on transferred
-			kitty.price = None;
-			kitty.owner = to.clone();
-			Kitties::<T>::insert(&kitty.dna, kitty.clone());
+			// 1. reset the price and the breeding fee of a kitty on transferred, unlocking the
+			// item of a listed kitty
+			if kitty.price.take().is_some() {
+				T::Nfts::enable_transfer(&collection, &item)?;
+				Kitties::<T>::insert(&kitty.dna, kitty.clone());
+				ForSale::<T>::remove(&kitty.dna);
+			}
+			SireFees::<T>::remove(&kitty.dna);
 
-			// 2. set the new owner for the kitty
-			<KittyOwner<T>>::insert(kitty.dna, Some(&to));
+			// 2. move the kitty item to its new owner, the deposit of the kitty stays with its
+			// depositor
+			T::Nfts::transfer(&collection, &item, &to)?;
 
-			// 3. push the new kitty DNA to the list of existing kitties owned by a destination account
-			KittiesOwned::<T>::try_append(&to, kitty.dna).map_err(|_| Error::<T>::TooManyOwned)?;
+			Ok(())
+		}
 
-			// 4. update the existing KittiesOwned list of the from account
-			ensure!(from != to, Error::<T>::TransferToSelf);
-			let mut from_owned = KittiesOwned::<T>::get(&from);
-			// Remove kitty from list of owned kitties.
-			if let Some(ind) = from_owned.iter().position(|&id| id == kitty.dna) {
-				from_owned.swap_remove(ind);
-			} else {
-				return Err(Error::<T>::NoKitty.into());
+		// Burns a kitty along with its item and releases its deposit. A kitty being auctioned
+		// cannot be burned. Offers made on the kitty are left for their buyers to withdraw. The
+		// owner is only checked while the item exists, as a kitty whose item was burned in
+		// `T::Nfts` directly has no owner left.
+		pub(crate) fn do_burn(
+			kitty_dna: &T::Hash,
+			maybe_check_owner: Option<&T::AccountId>,
+		) -> DispatchResult {
+			let kitty = Self::kitties(kitty_dna).ok_or(Error::<T>::NoKitty)?;
+			let (collection, item) = Self::collection_item(kitty_dna)?;
+			let owner = T::Nfts::owner(&collection, &item);
+			if let (Some(check_owner), Some(owner)) = (maybe_check_owner, owner.as_ref()) {
+				ensure!(owner == check_owner, Error::<T>::NotOwner);
+			}
+			ensure!(!Auctions::<T>::contains_key(kitty_dna), Error::<T>::KittyInAuction);
+
+			// 1. burn the kitty item unless it is burned already, unlocking it first if the kitty
+			// is listed
+			if kitty.price.is_some() {
+				T::Nfts::enable_transfer(&collection, &item)?;
+			}
+			T::Nfts::clear_typed_attribute(&collection, &item, b"dna")?;
+			T::Nfts::clear_typed_attribute(&collection, &item, b"gender")?;
+			T::Nfts::clear_typed_attribute(&collection, &item, b"generation")?;
+			if owner.is_some() {
+				T::Nfts::burn(&collection, &item, None)?;
+			}
+
+			// 2. release the deposit of the kitty, and free its name along with its deposit
+			if let Some((depositor, deposit)) = KittyDeposits::<T>::take(kitty_dna) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+			if let Some(metadata) = KittyMetadataOf::<T>::take(kitty_dna) {
+				Self::release_name(metadata);
 			}
-			KittiesOwned::<T>::insert(&from, from_owned);
+
+			// 3. forget the kitty
+			Kitties::<T>::remove(kitty_dna);
+			ForSale::<T>::remove(kitty_dna);
+			ItemOfKitty::<T>::remove(kitty_dna);
+			KittyOfItem::<T>::remove(item);
+			SireFees::<T>::remove(kitty_dna);
+			AllKittiesCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			Self::count_traits(kitty_dna, false);
 
 			Ok(())
 		}
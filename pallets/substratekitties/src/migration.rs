@@ -0,0 +1,198 @@
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use log;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{sp_runtime::TryRuntimeError, sp_std::vec::Vec, traits::Currency};
+
+pub mod v1 {
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{traits::Zero, Saturating},
+		storage::with_storage_layer,
+		weights::Weight,
+	};
+
+	use super::*;
+
+	/// The kitty as stored by the v0 storage layout.
+	#[derive(Encode, Decode)]
+	pub struct OldKitty<T: Config> {
+		pub dna: T::Hash,
+		pub price: Option<BalanceOf<T>>,
+		pub gender: Gender,
+		pub owner: T::AccountId,
+	}
+
+	impl<T: Config> OldKitty<T> {
+		/// Migrates the old kitty to the new v1 format, which leaves the owner to `T::Nfts`.
+		/// Existing kitties were never bred, so they become generation zero kitties without
+		/// parents, ready to breed right away.
+		fn migrate_to_v1(self) -> (Kitty<T>, T::AccountId) {
+			let kitty = Kitty {
+				dna: self.dna,
+				price: self.price,
+				gender: self.gender,
+				generation: 0,
+				parents: None,
+				born_at: Zero::zero(),
+				ready_at: Zero::zero(),
+				cooldown_index: 0,
+			};
+			(kitty, self.owner)
+		}
+	}
+
+	#[frame_support::storage_alias]
+	pub type Kitties<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::Hash, OldKitty<T>>;
+
+	#[frame_support::storage_alias]
+	pub type KittyOwner<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::Hash,
+		Option<<T as frame_system::Config>::AccountId>,
+		ValueQuery,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type KittiesOwned<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<<T as frame_system::Config>::Hash, <T as Config>::MaxKittiesOwned>,
+		ValueQuery,
+	>;
+
+	/// A migration utility to update the storage version from v0 to v1 for the pallet.
+	///
+	/// Mints the item of every existing kitty in `T::Nfts` to its owner, counts its traits in
+	/// `TraitCounts` and lists it in `ForSale` if it has a price. The redundant `KittyOwner`
+	/// map and the `KittiesOwned` map keyed by account are removed, as `T::Nfts` replaces them.
+	///
+	/// The item deposits are paid by the account of this pallet, which has to hold enough funds
+	/// before the upgrade. If any item cannot be minted, the whole migration is rolled back and
+	/// the storage stays at version 0, so that no kitty is lost. It then runs again with the
+	/// next runtime upgrade, once the pallet account is funded.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with current storage version {:?} / onchain {:?}",
+				current_version,
+				onchain_version
+			);
+
+			if onchain_version == 0 {
+				let mut translated = 0u64;
+				let migrated = with_storage_layer(|| -> Result<(), DispatchError> {
+					let mut failed = None;
+					crate::Kitties::<T>::translate::<OldKitty<T>, _>(|key, old_value| {
+						translated.saturating_inc();
+						let (kitty, owner) = old_value.migrate_to_v1();
+						if failed.is_none() {
+							match Pallet::<T>::mint_item(&owner, &kitty, true) {
+								Ok(()) => Pallet::<T>::count_traits(&kitty.dna, true),
+								Err(e) => failed = Some((key, e)),
+							}
+						}
+						Some(kitty)
+					});
+					if let Some((key, e)) = failed {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to mint the item of kitty {:?}, leaving the storage at version 0: {:?}",
+							key,
+							e
+						);
+						return Err(e)
+					}
+
+					let _ = KittyOwner::<T>::clear(u32::MAX, None);
+					let _ = KittiesOwned::<T>::clear(u32::MAX, None);
+
+					StorageVersion::new(1).put::<Pallet<T>>();
+					Ok(())
+				});
+
+				if migrated.is_ok() {
+					log::info!(
+						target: LOG_TARGET,
+						"Upgraded {} records, storage to version 1",
+						translated
+					);
+				}
+				// every minted item touches around ten storage items of `T::Nfts`, and every
+				// kitty has its traits counted
+				T::DbWeight::get().reads_writes(translated * 20 + 1, translated * 20 + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(
+				T::Currency::free_balance(&Pallet::<T>::account_id()) >
+					T::Currency::minimum_balance(),
+				"the pallet account should hold funds for the collection and item deposits"
+			);
+			let owners = Kitties::<T>::iter_values()
+				.map(|kitty| (kitty.dna, kitty.owner))
+				.collect::<Vec<_>>();
+			Ok(owners.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(owners: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let owners: Vec<(T::Hash, T::AccountId)> = Decode::decode(&mut owners.as_slice())
+				.expect(
+					"the state parameter should be something that was generated by pre_upgrade",
+				);
+			ensure!(
+				owners.len() as u64 == crate::Kitties::<T>::iter().count() as u64,
+				"the records count before and after the migration should be the same"
+			);
+			for (dna, owner) in owners {
+				ensure!(
+					Pallet::<T>::owner_of(&dna) == Some(owner),
+					"every kitty should be owned by the same account in T::Nfts"
+				);
+			}
+			ensure!(KittyOwner::<T>::iter_keys().next().is_none(), "KittyOwner should be empty");
+			ensure!(
+				KittiesOwned::<T>::iter_keys().next().is_none(),
+				"KittiesOwned should be empty"
+			);
+
+			// every kitty has exactly one value of each gene
+			let kitties = crate::Kitties::<T>::iter_keys().count() as u64;
+			for gene_table in crate::traits::table(crate::traits::TRAITS_VERSION)
+				.expect("the table of the current version exists; qed")
+			{
+				let counted: u64 =
+					crate::TraitCounts::<T>::iter_prefix_values(gene_table.gene).sum();
+				ensure!(counted == kitties, "every kitty should be counted once for each gene");
+			}
+			let listed =
+				crate::Kitties::<T>::iter_values().filter(|kitty| kitty.price.is_some()).count();
+			ensure!(
+				ForSale::<T>::iter_keys().count() == listed,
+				"every kitty with a price should be listed"
+			);
+
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
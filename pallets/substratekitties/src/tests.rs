@@ -0,0 +1,1794 @@
+use crate::{
+	mock::*, weights::WeightInfo, AuctionKind, Config, Error, Event, Gender, MintCommitment,
+	PendingMint,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchClass,
+	traits::{
+		tokens::nonfungibles_v2::{Inspect, Mutate, Transfer},
+		Currency, Get, Hooks,
+	},
+};
+use pallet_nfts::ItemConfig;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+// Gender is taken from the first byte of the DNA, even bytes give a Male kitty.
+const MALE: H256 = H256::repeat_byte(2);
+const FEMALE: H256 = H256::repeat_byte(1);
+const SECRET: H256 = H256::repeat_byte(7);
+
+// Mints a generation zero kitty with the given DNA.
+fn mint(owner: u64, dna: H256) -> H256 {
+	assert_ok!(Kitties::mint(&owner, dna, None, 0));
+	dna
+}
+
+// The commitment of `who` to mint with `secret`.
+fn commitment(who: u64, secret: H256) -> H256 {
+	BlakeTwo256::hash_of(&(who, secret))
+}
+
+// Reveals the mint commitment of `who`, once the block seeding it is sealed.
+fn reveal(who: u64, secret: H256) {
+	let committed_at = Kitties::mint_commitments(who).unwrap().committed_at;
+	System::set_block_number(System::block_number().max(committed_at + 3));
+	assert_ok!(Kitties::reveal_mint(RuntimeOrigin::signed(who), secret));
+}
+
+// Mints a generation zero kitty through a mint commitment of `owner`, and returns its DNA.
+fn create_kitty(owner: u64) -> H256 {
+	assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(owner), commitment(owner, SECRET)));
+	reveal(owner, SECRET);
+	created_kitty()
+}
+
+// Returns the DNA of the last kitty created with `reveal_mint`.
+fn created_kitty() -> H256 {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Kitties(Event::Created { kitty, .. }) => Some(kitty),
+			_ => None,
+		})
+		.expect("no kitty was created")
+}
+
+#[test]
+fn create_kitty_works() {
+	build_and_execute(|| {
+		let dna = create_kitty(ALICE);
+
+		assert_eq!(Kitties::all_kitties_count(), 1);
+		assert_eq!(Kitties::owner_of(&dna), Some(ALICE));
+		assert_eq!(Kitties::owned_count(&ALICE), 1);
+		let kitty = Kitties::kitties(dna).unwrap();
+		assert_eq!(kitty.price, None);
+		assert_eq!(kitty.generation, 0);
+		assert_eq!(kitty.parents, None);
+		// the kitty is minted into the kitties collection, owned by the pallet account
+		let collection = Kitties::kitties_collection().unwrap();
+		let item = Kitties::item_of_kitty(dna).unwrap();
+		assert_eq!(Kitties::kitty_of_item(item), Some(dna));
+		assert_eq!(
+			<Nfts as Inspect<u64>>::collection_owner(&collection),
+			Some(Kitties::account_id())
+		);
+		System::assert_last_event(Event::Created { kitty: dna, owner: ALICE }.into());
+	});
+}
+
+#[test]
+fn create_kitty_fails_for_duplicate_kitty() {
+	build_and_execute(|| {
+		mint(ALICE, MALE);
+		assert_noop!(Kitties::mint(&BOB, MALE, None, 0), Error::<Test>::DuplicateKitty);
+	});
+}
+
+#[test]
+fn create_kitties_works() {
+	build_and_execute(|| {
+		assert_ok!(Kitties::create_kitties(
+			RuntimeOrigin::signed(ALICE),
+			3,
+			commitment(ALICE, SECRET)
+		));
+		assert_eq!(Kitties::mint_commitments(ALICE).unwrap().mint, PendingMint::Kitties(3));
+		assert_eq!(Kitties::all_kitties_count(), 0);
+
+		// every kitty is minted by the reveal, with its own DNA
+		reveal(ALICE, SECRET);
+		assert_eq!(Kitties::all_kitties_count(), 3);
+		assert_eq!(Kitties::owned_count(&ALICE), 3);
+		let created = System::events()
+			.into_iter()
+			.filter(|record| matches!(record.event, RuntimeEvent::Kitties(Event::Created { .. })))
+			.count();
+		assert_eq!(created, 3);
+	});
+}
+
+#[test]
+fn create_kitties_fails_for_too_many_owned() {
+	build_and_execute(|| {
+		// no kitty is created unless all of them can be
+		create_kitty(ALICE);
+		assert_noop!(
+			Kitties::create_kitties(RuntimeOrigin::signed(ALICE), 3, commitment(ALICE, SECRET)),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn create_kitties_fails_for_invalid_count() {
+	build_and_execute(|| {
+		assert_noop!(
+			Kitties::create_kitties(RuntimeOrigin::signed(ALICE), 0, commitment(ALICE, SECRET)),
+			Error::<Test>::InvalidMintCount
+		);
+		// a commitment cannot mint more than `MaxMintPerCommit` kitties
+		assert_noop!(
+			Kitties::create_kitties(RuntimeOrigin::signed(ALICE), 4, commitment(ALICE, SECRET)),
+			Error::<Test>::InvalidMintCount
+		);
+	});
+}
+
+#[test]
+fn create_kitty_fails_for_too_many_owned() {
+	build_and_execute(|| {
+		for _ in 0..3 {
+			create_kitty(ALICE);
+		}
+		assert_noop!(
+			Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, SECRET)),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn transfer_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::owned_count(&ALICE), 0);
+		assert_eq!(Kitties::owned_count(&BOB), 1);
+		// the transfer takes the kitty off the market
+		assert_eq!(Kitties::kitties(dna).unwrap().price, None);
+		System::assert_last_event(Event::Transferred { from: ALICE, to: BOB, kitty: dna }.into());
+	});
+}
+
+#[test]
+fn transfer_fails_for_unknown_kitty() {
+	build_and_execute(|| {
+		assert_noop!(
+			Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, MALE),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn transfer_fails_for_not_owner() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::transfer(RuntimeOrigin::signed(BOB), CHARLIE, dna),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_fails_for_transfer_to_self() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::transfer(RuntimeOrigin::signed(ALICE), ALICE, dna),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn transfer_works_beyond_max_kitties_owned() {
+	build_and_execute(|| {
+		for byte in 1..=3 {
+			mint(BOB, H256::repeat_byte(byte));
+		}
+		let dna = mint(ALICE, H256::repeat_byte(4));
+
+		// `MaxKittiesOwned` only stops an account from minting or breeding more kitties
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna));
+		assert_eq!(Kitties::owned_count(&BOB), 4);
+		assert_noop!(
+			Kitties::commit_mint(RuntimeOrigin::signed(BOB), commitment(BOB, SECRET)),
+			Error::<Test>::TooManyOwned
+		);
+	});
+}
+
+#[test]
+fn transfer_keeps_the_kitty_deposit() {
+	build_and_execute(|| {
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		let dna = mint(ALICE, MALE);
+		assert_eq!(Kitties::kitty_deposit(dna), Some((ALICE, KittyDeposit::get())));
+		// the item deposit of `T::Nfts` is reserved along with the kitty deposit
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved + 1 + KittyDeposit::get());
+		let alice_reserved = Balances::reserved_balance(ALICE);
+
+		// the recipient of a transfer pays nothing, not even without any funds
+		Balances::make_free_balance_be(&BOB, 0);
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::kitty_deposit(dna), Some((ALICE, KittyDeposit::get())));
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+
+		// both deposits go back to their depositor when the new owner burns the kitty
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(BOB), dna));
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved - 1 - KittyDeposit::get());
+	});
+}
+
+#[test]
+fn reveal_mint_works_beyond_max_kitties_owned() {
+	build_and_execute(|| {
+		mint(ALICE, MALE);
+		mint(ALICE, FEMALE);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, SECRET)));
+		let dna = mint(BOB, H256::repeat_byte(4));
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(BOB), ALICE, dna));
+
+		// `MaxKittiesOwned` was checked when committing
+		reveal(ALICE, SECRET);
+		assert_eq!(Kitties::owned_count(&ALICE), 4);
+	});
+}
+
+#[test]
+fn set_price_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		assert_eq!(Kitties::kitties(dna).unwrap().price, Some(10));
+		System::assert_last_event(Event::PriceSet { kitty: dna, price: Some(10) }.into());
+
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, None));
+		assert_eq!(Kitties::kitties(dna).unwrap().price, None);
+		System::assert_last_event(Event::PriceSet { kitty: dna, price: None }.into());
+	});
+}
+
+#[test]
+fn kitty_items_are_locked_while_listed() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		let (collection, item) = Kitties::collection_item(&dna).unwrap();
+
+		// a listed kitty cannot be moved or burned around the kitties pallet
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		assert_noop!(
+			<Nfts as Transfer<u64>>::transfer(&collection, &item, &BOB),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+		assert_noop!(
+			<Nfts as Mutate<u64, ItemConfig>>::burn(&collection, &item, Some(&ALICE)),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+
+		// once unlisted, the item is an NFT like any other
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, None));
+		assert_ok!(<Nfts as Transfer<u64>>::transfer(&collection, &item, &BOB));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_ok!(Kitties::do_try_state());
+	});
+}
+
+#[test]
+fn set_price_fails_for_unknown_kitty() {
+	build_and_execute(|| {
+		assert_noop!(
+			Kitties::set_price(RuntimeOrigin::signed(ALICE), MALE, Some(10)),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn set_price_fails_for_not_owner() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::set_price(RuntimeOrigin::signed(BOB), dna, Some(10)),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+
+		assert_ok!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 10));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::kitties(dna).unwrap().price, None);
+		// the seller gets the price minus the protocol fee, which goes to the treasury, and the
+		// deposit of the kitty moves to the buyer
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 9 + KittyDeposit::get());
+		assert_eq!(Balances::free_balance(TREASURY), 1);
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 10 - KittyDeposit::get());
+		assert_eq!(Kitties::kitty_deposit(dna), Some((BOB, KittyDeposit::get())));
+		System::assert_last_event(
+			Event::Sold { seller: ALICE, buyer: BOB, kitty: dna, price: 10, fee: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_charges_the_ask_price() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(20)));
+		let bob_balance = Balances::free_balance(BOB);
+
+		// bidding more than the ask price only charges the ask price
+		assert_ok!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 50));
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 20 - KittyDeposit::get());
+		System::assert_last_event(
+			Event::Sold { seller: ALICE, buyer: BOB, kitty: dna, price: 20, fee: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_unknown_kitty() {
+	build_and_execute(|| {
+		assert_noop!(
+			Kitties::buy_kitty(RuntimeOrigin::signed(BOB), MALE, 10),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_not_for_sale() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 10),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_bid_price_too_low() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		assert_noop!(
+			Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 9),
+			Error::<Test>::BidPriceTooLow
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_transfer_to_self() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		assert_noop!(
+			Kitties::buy_kitty(RuntimeOrigin::signed(ALICE), dna, 10),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn buy_kitty_fails_for_insufficient_balance() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(1000)));
+		assert!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 1000).is_err());
+		assert_eq!(Kitties::owner_of(&dna), Some(ALICE));
+	});
+}
+
+#[test]
+fn buy_kitty_failing_payment_moves_no_funds() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		// the buyer can take over the deposit, but cannot pay the price on top of it
+		Balances::make_free_balance_be(&BOB, 12);
+		let balances = || {
+			[ALICE, BOB, TREASURY]
+				.map(|who| (Balances::free_balance(who), Balances::reserved_balance(who)))
+		};
+		let before = balances();
+
+		assert!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), dna, 10).is_err());
+		assert_eq!(balances(), before);
+		assert_eq!(Kitties::owner_of(&dna), Some(ALICE));
+		assert_eq!(Kitties::kitty_deposit(dna), Some((ALICE, KittyDeposit::get())));
+	});
+}
+
+#[test]
+fn breed_kitty_works() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(ALICE, FEMALE);
+
+		assert_ok!(Kitties::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			male,
+			female,
+			commitment(ALICE, SECRET)
+		));
+		assert_eq!(
+			Kitties::mint_commitments(ALICE).unwrap().mint,
+			PendingMint::Child { parents: (male, female), generation: 1 }
+		);
+		// both parents are on cooldown for `BaseBreedingCooldown` blocks from the commitment
+		assert_eq!(Kitties::kitties(male).unwrap().ready_at, 11);
+		assert_eq!(Kitties::kitties(female).unwrap().cooldown_index, 1);
+
+		// the child is born once the commitment is revealed
+		reveal(ALICE, SECRET);
+		let child = crate::KittyChildren::<Test>::iter_key_prefix(male).next().unwrap();
+		System::assert_last_event(
+			Event::Bred { parents: (male, female), child, owner: ALICE }.into(),
+		);
+
+		assert_eq!(Kitties::all_kitties_count(), 3);
+		assert_eq!(Kitties::owner_of(&child), Some(ALICE));
+		let kitty = Kitties::kitties(child).unwrap();
+		assert_eq!(kitty.generation, 1);
+		assert_eq!(kitty.parents, Some((male, female)));
+		assert!(crate::KittyChildren::<Test>::contains_key(male, child));
+		assert!(crate::KittyChildren::<Test>::contains_key(female, child));
+		// every bit of the child comes from one of its parents
+		for (i, byte) in child.as_bytes().iter().enumerate() {
+			assert_eq!(byte & !(MALE[i] | FEMALE[i]), 0);
+		}
+	});
+}
+
+#[test]
+fn breed_kitty_is_cancelled_for_released_parent() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(ALICE, FEMALE);
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(Kitties::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			male,
+			female,
+			commitment(ALICE, SECRET)
+		));
+
+		// the parent is gone by the time the child would be born
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(ALICE), female));
+		let alice_balance = alice_balance + 1 + KittyDeposit::get();
+		reveal(ALICE, SECRET);
+		System::assert_last_event(
+			Event::BreedingCancelled { parents: (male, female), owner: ALICE }.into(),
+		);
+		assert_eq!(Kitties::all_kitties_count(), 1);
+		assert!(crate::KittyChildren::<Test>::iter_key_prefix(male).next().is_none());
+		assert_eq!(Kitties::mint_commitments(ALICE), None);
+		// the deposit of the commitment is refunded
+		assert_eq!(Balances::free_balance(ALICE), alice_balance);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_unknown_kitty() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				male,
+				FEMALE,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::NoKitty
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_not_owner() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(BOB, FEMALE);
+		assert_noop!(
+			Kitties::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				male,
+				female,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_same_gender() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let other_male = mint(ALICE, H256::repeat_byte(4));
+		assert_noop!(
+			Kitties::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				male,
+				other_male,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::SameGender
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_kitty_on_cooldown() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(ALICE, FEMALE);
+		assert_ok!(Kitties::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			male,
+			female,
+			commitment(ALICE, SECRET)
+		));
+		reveal(ALICE, SECRET);
+
+		let child = crate::KittyChildren::<Test>::iter_key_prefix(male).next().unwrap();
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, child));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Kitties::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				male,
+				female,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::KittyOnCooldown
+		);
+
+		// the breeding cooldown doubles after every breeding
+		System::set_block_number(11);
+		assert_ok!(Kitties::breed_kitty(
+			RuntimeOrigin::signed(ALICE),
+			male,
+			female,
+			commitment(ALICE, SECRET)
+		));
+		assert_eq!(Kitties::kitties(male).unwrap().ready_at, 31);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_for_pending_commitment() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(ALICE, FEMALE);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, SECRET)));
+
+		// the parents stay off cooldown
+		assert_noop!(
+			Kitties::breed_kitty(
+				RuntimeOrigin::signed(ALICE),
+				male,
+				female,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::AlreadyCommitted
+		);
+	});
+}
+
+#[test]
+fn offer_sire_works() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_eq!(Kitties::sire_fee(male), Some((ALICE, 5)));
+		System::assert_last_event(Event::SireOffered { kitty: male, fee: Some(5) }.into());
+
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, None));
+		assert_eq!(Kitties::sire_fee(male), None);
+		System::assert_last_event(Event::SireOffered { kitty: male, fee: None }.into());
+	});
+}
+
+#[test]
+fn offer_sire_fails_for_not_owner() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::offer_sire(RuntimeOrigin::signed(BOB), male, Some(5)),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn offer_sire_fails_for_wrong_gender() {
+	build_and_execute(|| {
+		let female = mint(ALICE, FEMALE);
+		assert_eq!(Kitties::kitties(female).unwrap().gender, Gender::Female);
+		assert_noop!(
+			Kitties::offer_sire(RuntimeOrigin::signed(ALICE), female, Some(5)),
+			Error::<Test>::WrongGender
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_works() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(BOB, FEMALE);
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(Kitties::breed_with_sire(
+			RuntimeOrigin::signed(BOB),
+			female,
+			male,
+			commitment(BOB, SECRET)
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 5);
+		// the child goes to the owner of the female
+		reveal(BOB, SECRET);
+		assert_eq!(Kitties::owned_count(&BOB), 2);
+		assert_eq!(Kitties::owned_count(&ALICE), 1);
+		assert_eq!(Kitties::kitties(male).unwrap().cooldown_index, 1);
+	});
+}
+
+#[test]
+fn breed_with_sire_fails_for_not_offered_as_sire() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(BOB, FEMALE);
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				female,
+				male,
+				commitment(BOB, SECRET)
+			),
+			Error::<Test>::NotOfferedAsSire
+		);
+
+		// the offer is dropped when the sire changes hands
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, male));
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				female,
+				male,
+				commitment(BOB, SECRET)
+			),
+			Error::<Test>::NotOfferedAsSire
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_fails_for_wrong_gender() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let other_male = mint(BOB, H256::repeat_byte(4));
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				other_male,
+				male,
+				commitment(BOB, SECRET)
+			),
+			Error::<Test>::WrongGender
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_fails_for_not_owner() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(CHARLIE, FEMALE);
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				female,
+				male,
+				commitment(BOB, SECRET)
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_fails_for_transfer_to_self() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(ALICE, FEMALE);
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(ALICE),
+				female,
+				male,
+				commitment(ALICE, SECRET)
+			),
+			Error::<Test>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn breed_with_sire_fails_for_kitty_on_cooldown() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(BOB, FEMALE);
+		assert_ok!(Kitties::offer_sire(RuntimeOrigin::signed(ALICE), male, Some(5)));
+		assert_ok!(Kitties::breed_with_sire(
+			RuntimeOrigin::signed(BOB),
+			female,
+			male,
+			commitment(BOB, SECRET)
+		));
+		assert_noop!(
+			Kitties::breed_with_sire(
+				RuntimeOrigin::signed(BOB),
+				female,
+				male,
+				commitment(BOB, SECRET)
+			),
+			Error::<Test>::KittyOnCooldown
+		);
+	});
+}
+
+// Puts a kitty of ALICE up for auction, ending at block 11.
+fn auction(kind: AuctionKind, start_price: u64, reserve: u64) -> H256 {
+	let dna = mint(ALICE, MALE);
+	assert_ok!(Kitties::create_auction(
+		RuntimeOrigin::signed(ALICE),
+		dna,
+		kind,
+		start_price,
+		reserve,
+		10
+	));
+	dna
+}
+
+#[test]
+fn create_auction_works() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 20);
+
+		let auction = Kitties::auctions(dna).unwrap();
+		assert_eq!(auction.seller, ALICE);
+		assert_eq!((auction.start, auction.end), (1, 11));
+		assert_eq!(crate::AuctionsEndingAt::<Test>::get(11).into_inner(), vec![dna]);
+		System::assert_last_event(
+			Event::AuctionCreated {
+				kitty: dna,
+				seller: ALICE,
+				kind: AuctionKind::English,
+				start_price: 10,
+				reserve: 20,
+				end: 11,
+			}
+			.into(),
+		);
+
+		// the kitty cannot change hands while it is auctioned
+		let (collection, item) = Kitties::collection_item(&dna).unwrap();
+		assert_noop!(
+			<Nfts as Transfer<u64>>::transfer(&collection, &item, &BOB),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+		assert_noop!(
+			Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+#[test]
+fn create_auction_fails_for_invalid_auctions() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		let create = |who, kind, start_price, reserve, duration| {
+			Kitties::create_auction(
+				RuntimeOrigin::signed(who),
+				dna,
+				kind,
+				start_price,
+				reserve,
+				duration,
+			)
+		};
+
+		assert_noop!(create(BOB, AuctionKind::English, 10, 10, 10), Error::<Test>::NotOwner);
+		assert_noop!(
+			create(ALICE, AuctionKind::English, 10, 10, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			create(ALICE, AuctionKind::English, 10, 10, 101),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			create(ALICE, AuctionKind::Dutch, 10, 20, 10),
+			Error::<Test>::InvalidAuctionPrice
+		);
+
+		assert_ok!(create(ALICE, AuctionKind::English, 10, 20, 10));
+		assert_noop!(
+			create(ALICE, AuctionKind::English, 10, 20, 10),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+#[test]
+fn create_auction_fails_for_kitty_on_sale() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+		assert_noop!(
+			Kitties::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				dna,
+				AuctionKind::English,
+				10,
+				10,
+				10
+			),
+			Error::<Test>::KittyOnSale
+		);
+	});
+}
+
+#[test]
+fn create_auction_fails_for_too_many_auctions() {
+	build_and_execute(|| {
+		let create = |dna| {
+			Kitties::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				dna,
+				AuctionKind::English,
+				10,
+				10,
+				10,
+			)
+		};
+		assert_ok!(create(mint(ALICE, H256::repeat_byte(1))));
+		assert_ok!(create(mint(ALICE, H256::repeat_byte(2))));
+
+		// only two auctions can end in the same block
+		let dna = mint(ALICE, H256::repeat_byte(3));
+		assert_noop!(create(dna), Error::<Test>::TooManyAuctions);
+	});
+}
+
+#[test]
+fn english_auction_works() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 15);
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 10));
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+		System::assert_last_event(Event::BidPlaced { kitty: dna, bidder: BOB, amount: 10 }.into());
+
+		// a better bid releases the one it beats
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(CHARLIE), dna, 20));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		let charlie_reserved = Balances::reserved_balance(CHARLIE);
+		let charlie_balance = Balances::free_balance(CHARLIE);
+
+		// the auction is settled at the start of its last block
+		System::set_block_number(11);
+		Kitties::on_initialize(11);
+		assert_eq!(Kitties::owner_of(&dna), Some(CHARLIE));
+		assert_eq!(Kitties::auctions(dna), None);
+		assert_eq!(
+			Balances::reserved_balance(CHARLIE),
+			charlie_reserved - 20 + KittyDeposit::get()
+		);
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_balance - KittyDeposit::get());
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 18 + KittyDeposit::get());
+		assert_eq!(Balances::free_balance(TREASURY), 2);
+		System::assert_last_event(
+			Event::AuctionSettled { kitty: dna, winner: Some(CHARLIE) }.into(),
+		);
+	});
+}
+
+#[test]
+fn english_auction_below_reserve_keeps_the_kitty() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 50);
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 20));
+
+		System::set_block_number(11);
+		Kitties::on_initialize(11);
+		assert_eq!(Kitties::owner_of(&dna), Some(ALICE));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		System::assert_last_event(Event::AuctionSettled { kitty: dna, winner: None }.into());
+
+		// the kitty can be moved again
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna));
+	});
+}
+
+#[test]
+fn english_auction_with_failing_sale_keeps_the_kitty() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 10);
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 20));
+		// the bidder spends everything but the bid before the auction ends, and cannot pay for
+		// the deposit of the kitty
+		Balances::make_free_balance_be(&BOB, 1);
+
+		System::set_block_number(11);
+		Kitties::on_initialize(11);
+		assert_eq!(Kitties::owner_of(&dna), Some(ALICE));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 21);
+		System::assert_last_event(Event::AuctionSettled { kitty: dna, winner: None }.into());
+	});
+}
+
+#[test]
+fn bid_fails_for_invalid_bids() {
+	build_and_execute(|| {
+		assert_noop!(Kitties::bid(RuntimeOrigin::signed(BOB), MALE, 10), Error::<Test>::NoAuction);
+
+		let dna = auction(AuctionKind::English, 10, 10);
+		assert_noop!(
+			Kitties::bid(RuntimeOrigin::signed(ALICE), dna, 10),
+			Error::<Test>::TransferToSelf
+		);
+		assert_noop!(
+			Kitties::bid(RuntimeOrigin::signed(BOB), dna, 9),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 10));
+		assert_noop!(
+			Kitties::bid(RuntimeOrigin::signed(CHARLIE), dna, 10),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Kitties::bid(RuntimeOrigin::signed(CHARLIE), dna, 20),
+			Error::<Test>::AuctionEnded
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_works() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::Dutch, 50, 10);
+		let bob_balance = Balances::free_balance(BOB);
+
+		// half way through, the price is half way down to the reserve
+		System::set_block_number(6);
+		assert_eq!(Kitties::auctions(dna).unwrap().dutch_price(6), 30);
+		assert_noop!(
+			Kitties::bid(RuntimeOrigin::signed(BOB), dna, 29),
+			Error::<Test>::BidPriceTooLow
+		);
+
+		// the first bid covering the price buys the kitty for that price
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 40));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::auctions(dna), None);
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 30 - KittyDeposit::get());
+		System::assert_last_event(Event::AuctionSettled { kitty: dna, winner: Some(BOB) }.into());
+		assert!(crate::AuctionsEndingAt::<Test>::get(11).is_empty());
+
+		// the new owner can auction the kitty again, and the new auction is not settled at the
+		// end of the first one
+		assert_ok!(Kitties::create_auction(
+			RuntimeOrigin::signed(BOB),
+			dna,
+			AuctionKind::Dutch,
+			50,
+			10,
+			10
+		));
+		System::set_block_number(11);
+		Kitties::on_initialize(11);
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::auctions(dna).unwrap().end, 16);
+
+		// and it can still be bought before it ends
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(CHARLIE), dna, 50));
+		assert_eq!(Kitties::owner_of(&dna), Some(CHARLIE));
+		assert!(crate::AuctionsEndingAt::<Test>::get(16).is_empty());
+	});
+}
+
+#[test]
+fn settle_auction_works() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 10);
+		assert_ok!(Kitties::bid(RuntimeOrigin::signed(BOB), dna, 10));
+		assert_noop!(
+			Kitties::settle_auction(RuntimeOrigin::signed(CHARLIE), dna),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		// anyone can settle an auction which was not settled at its end
+		System::set_block_number(12);
+		assert_ok!(Kitties::settle_auction(RuntimeOrigin::signed(CHARLIE), dna));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert!(crate::AuctionsEndingAt::<Test>::get(11).is_empty());
+		assert_noop!(
+			Kitties::settle_auction(RuntimeOrigin::signed(CHARLIE), dna),
+			Error::<Test>::NoAuction
+		);
+	});
+}
+
+#[test]
+fn make_offer_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 10, 5));
+		assert_eq!(Kitties::offers(dna, BOB), Some((10, 5)));
+		assert_eq!(Balances::reserved_balance(BOB), 10);
+		System::assert_last_event(
+			Event::OfferMade { kitty: dna, buyer: BOB, amount: 10, expires_at: 5 }.into(),
+		);
+
+		// a new offer replaces the previous one
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 20, 8));
+		assert_eq!(Kitties::offers(dna, BOB), Some((20, 8)));
+		assert_eq!(Balances::reserved_balance(BOB), 20);
+	});
+}
+
+#[test]
+fn make_offer_fails_for_invalid_offers() {
+	build_and_execute(|| {
+		assert_noop!(
+			Kitties::make_offer(RuntimeOrigin::signed(BOB), MALE, 10, 5),
+			Error::<Test>::NoKitty
+		);
+
+		let dna = mint(ALICE, MALE);
+		assert_noop!(
+			Kitties::make_offer(RuntimeOrigin::signed(ALICE), dna, 10, 5),
+			Error::<Test>::TransferToSelf
+		);
+		assert_noop!(
+			Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 10, 1),
+			Error::<Test>::OfferExpired
+		);
+		assert!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 1000, 5).is_err());
+	});
+}
+
+#[test]
+fn accept_offer_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 20, 5));
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+
+		assert_ok!(Kitties::accept_offer(RuntimeOrigin::signed(ALICE), dna, BOB));
+		assert_eq!(Kitties::owner_of(&dna), Some(BOB));
+		assert_eq!(Kitties::offers(dna, BOB), None);
+		assert_eq!(Balances::reserved_balance(BOB), KittyDeposit::get());
+		assert_eq!(Balances::free_balance(BOB), bob_balance - KittyDeposit::get());
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 18 + KittyDeposit::get());
+		System::assert_last_event(
+			Event::Sold { seller: ALICE, buyer: BOB, kitty: dna, price: 20, fee: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn accept_offer_fails_for_invalid_offers() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 20, 5));
+
+		assert_noop!(
+			Kitties::accept_offer(RuntimeOrigin::signed(CHARLIE), dna, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Kitties::accept_offer(RuntimeOrigin::signed(ALICE), dna, CHARLIE),
+			Error::<Test>::NoOffer
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Kitties::accept_offer(RuntimeOrigin::signed(ALICE), dna, BOB),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn accept_offer_fails_for_kitty_in_auction() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 10);
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 20, 5));
+		assert_noop!(
+			Kitties::accept_offer(RuntimeOrigin::signed(ALICE), dna, BOB),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+#[test]
+fn withdraw_offer_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::make_offer(RuntimeOrigin::signed(BOB), dna, 20, 5));
+
+		// expired offers can still be withdrawn
+		System::set_block_number(5);
+		assert_ok!(Kitties::withdraw_offer(RuntimeOrigin::signed(BOB), dna));
+		assert_eq!(Kitties::offers(dna, BOB), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		System::assert_last_event(Event::OfferWithdrawn { kitty: dna, buyer: BOB }.into());
+
+		assert_noop!(
+			Kitties::withdraw_offer(RuntimeOrigin::signed(BOB), dna),
+			Error::<Test>::NoOffer
+		);
+	});
+}
+
+#[test]
+fn kitties_of_works() {
+	build_and_execute(|| {
+		let male = mint(ALICE, MALE);
+		let female = mint(BOB, FEMALE);
+
+		assert_eq!(Kitties::kitties_of(&ALICE), vec![male]);
+		assert_eq!(Kitties::kitties_of(&BOB), vec![female]);
+		assert!(Kitties::kitties_of(&CHARLIE).is_empty());
+		assert_eq!(Kitties::kitty_with_owner(&female).map(|(_, owner)| owner), Some(BOB));
+	});
+}
+
+#[test]
+fn kitties_for_sale_is_paginated() {
+	build_and_execute(|| {
+		let listed: Vec<H256> = (1..=3)
+			.map(|byte| mint(ALICE, H256::repeat_byte(byte * 2)))
+			.inspect(|dna| {
+				assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), *dna, Some(10)))
+			})
+			.collect();
+		mint(BOB, FEMALE);
+
+		let first = Kitties::kitties_for_sale(0, 2);
+		let second = Kitties::kitties_for_sale(1, 2);
+		assert_eq!(first.len(), 2);
+		assert_eq!(second.len(), 1);
+		assert!(Kitties::kitties_for_sale(2, 2).is_empty());
+
+		let mut all = [first, second].concat();
+		all.sort();
+		assert_eq!(all, listed);
+
+		// kitties leave the listings when they are sold, unlisted or released
+		assert_ok!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), listed[0], 10));
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), listed[1], None));
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(ALICE), listed[2]));
+		assert!(Kitties::kitties_for_sale(0, 10).is_empty());
+	});
+}
+
+#[test]
+fn commit_and_reveal_mint_works() {
+	build_and_execute(|| {
+		let secret = H256::repeat_byte(7);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+		assert_eq!(
+			Kitties::mint_commitments(ALICE),
+			Some(MintCommitment {
+				commitment: commitment(ALICE, secret),
+				committed_at: 1,
+				deposit: 3,
+				mint: PendingMint::Kitties(1),
+			})
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 3);
+		System::assert_last_event(
+			Event::MintCommitted {
+				who: ALICE,
+				commitment: commitment(ALICE, secret),
+				reveal_after: 3,
+			}
+			.into(),
+		);
+
+		System::set_block_number(4);
+		assert_ok!(Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), secret));
+		let dna = created_kitty();
+		assert_eq!(Kitties::all_kitties_count(), 1);
+		assert_eq!(Kitties::kitties_of(&ALICE), vec![dna]);
+		assert_eq!(Kitties::mint_commitments(ALICE), None);
+		assert!(crate::MintCommitmentsExpiringAt::<Test>::get(11).is_empty());
+		// the commitment deposit is released, only the kitty deposit stays reserved
+		assert_eq!(Balances::reserved_balance(ALICE), KittyDeposit::get());
+	});
+}
+
+#[test]
+fn commit_mint_fails_for_already_committed() {
+	build_and_execute(|| {
+		let secret = H256::repeat_byte(7);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+		assert_noop!(
+			Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)),
+			Error::<Test>::AlreadyCommitted
+		);
+	});
+}
+
+#[test]
+fn commit_mint_fails_for_too_many_commitments() {
+	build_and_execute(|| {
+		let secret = H256::repeat_byte(7);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(BOB), commitment(BOB, secret)));
+		assert_noop!(
+			Kitties::commit_mint(RuntimeOrigin::signed(CHARLIE), commitment(CHARLIE, secret)),
+			Error::<Test>::TooManyMintCommitments
+		);
+	});
+}
+
+#[test]
+fn reveal_mint_fails_for_bad_reveals() {
+	build_and_execute(|| {
+		let secret = H256::repeat_byte(7);
+		assert_noop!(
+			Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), secret),
+			Error::<Test>::NoMintCommitment
+		);
+
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+		// the seed block 3 has to be sealed first
+		System::set_block_number(3);
+		assert_noop!(
+			Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), secret),
+			Error::<Test>::RevealTooEarly
+		);
+
+		System::set_block_number(4);
+		assert_noop!(
+			Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), H256::repeat_byte(8)),
+			Error::<Test>::InvalidReveal
+		);
+		// a commitment copied from another account cannot be revealed either
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(BOB), commitment(ALICE, secret)));
+		System::set_block_number(7);
+		assert_noop!(
+			Kitties::reveal_mint(RuntimeOrigin::signed(BOB), secret),
+			Error::<Test>::InvalidReveal
+		);
+	});
+}
+
+#[test]
+fn reveal_mint_fits_in_a_block() {
+	build_and_execute(|| {
+		let max_count = <Test as Config>::MaxMintPerCommit::get();
+		let max_weight = <Test as Config>::WeightInfo::reveal_mint(max_count);
+		let block_weights = <Test as frame_system::Config>::BlockWeights::get();
+		assert!(max_weight.all_lte(block_weights.max_block));
+		assert!(max_weight.all_lte(block_weights.get(DispatchClass::Normal).max_extrinsic.unwrap()));
+
+		// the weight of the kitties which are not minted is refunded
+		let secret = H256::repeat_byte(7);
+		assert_ok!(Kitties::create_kitties(
+			RuntimeOrigin::signed(ALICE),
+			2,
+			commitment(ALICE, secret)
+		));
+		System::set_block_number(4);
+		let info = Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), secret).unwrap();
+		assert_eq!(info.actual_weight, Some(<Test as Config>::WeightInfo::reveal_mint(2)));
+	});
+}
+
+#[test]
+fn mint_commitments_expire() {
+	build_and_execute(|| {
+		let secret = H256::repeat_byte(7);
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+
+		System::set_block_number(11);
+		Kitties::on_initialize(11);
+		assert_eq!(Kitties::mint_commitments(ALICE), None);
+		// the deposit of the commitment is forfeited
+		System::assert_last_event(Event::MintCommitmentExpired { who: ALICE, deposit: 3 }.into());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 97);
+		assert_noop!(
+			Kitties::reveal_mint(RuntimeOrigin::signed(ALICE), secret),
+			Error::<Test>::NoMintCommitment
+		);
+
+		// the account can commit again once its commitment expired
+		assert_ok!(Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, secret)));
+	});
+}
+
+#[test]
+fn genesis_config_mints_kitties() {
+	new_test_ext_with_kitties(vec![
+		(ALICE, MALE, Gender::Female, Some(10)),
+		(BOB, FEMALE, Gender::Male, None),
+	])
+	.execute_with(|| {
+		assert_eq!(Kitties::all_kitties_count(), 2);
+		assert_eq!(Kitties::kitties_of(&ALICE), vec![MALE]);
+		assert_eq!(Kitties::kitties_of(&BOB), vec![FEMALE]);
+
+		// the genesis gender wins over the one of the DNA
+		let kitty = Kitties::kitties(MALE).unwrap();
+		assert_eq!(kitty.gender, Gender::Female);
+		assert_eq!(kitty.price, Some(10));
+
+		// kitties listed at genesis can be bought right away
+		assert_eq!(Kitties::kitties_for_sale(0, 10), vec![MALE]);
+		assert_ok!(Kitties::buy_kitty(RuntimeOrigin::signed(BOB), MALE, 10));
+		assert_eq!(Kitties::owner_of(&MALE), Some(BOB));
+	});
+}
+
+// Stores a kitty of `owner` the way the pallet did before its first migration.
+fn insert_v0_kitty(owner: u64, dna: H256, gender: Gender, price: Option<u64>) {
+	use crate::migration::v1;
+
+	v1::Kitties::<Test>::insert(dna, v1::OldKitty::<Test> { dna, price, gender, owner });
+	v1::KittyOwner::<Test>::insert(dna, Some(owner));
+	v1::KittiesOwned::<Test>::mutate(owner, |owned| owned.try_push(dna).unwrap());
+	crate::AllKittiesCount::<Test>::mutate(|count| *count += 1);
+}
+
+#[test]
+fn migrate_to_v1_upgrades_v0_storage() {
+	use crate::{migration::v1, traits::Gene};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Kitties>();
+		insert_v0_kitty(ALICE, MALE, Gender::Male, Some(10));
+		insert_v0_kitty(BOB, FEMALE, Gender::Female, None);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Kitties::on_chain_storage_version(), 1);
+		assert_eq!(Kitties::all_kitties_count(), 2);
+		// the kitties are generation zero kitties without parents
+		let kitty = Kitties::kitties(MALE).unwrap();
+		assert_eq!((kitty.dna, kitty.price, kitty.gender), (MALE, Some(10), Gender::Male));
+		assert_eq!((kitty.generation, kitty.parents), (0, None));
+		assert_eq!((kitty.born_at, kitty.ready_at, kitty.cooldown_index), (0, 0, 0));
+		// the owners are kept by the items of the kitties
+		assert_eq!(Kitties::owner_of(&MALE), Some(ALICE));
+		assert_eq!(Kitties::owner_of(&FEMALE), Some(BOB));
+		assert_eq!(v1::KittyOwner::<Test>::iter_keys().count(), 0);
+		assert_eq!(v1::KittiesOwned::<Test>::iter_keys().count(), 0);
+		// the traits of the kitties are counted, and the listed kitty is indexed for sale
+		assert_eq!(Kitties::trait_count(Gene::BodyColour, 0), 2);
+		assert_eq!(Kitties::kitties_for_sale(0, 10), vec![MALE]);
+		// the listed kitty stays locked in `T::Nfts`
+		let (collection, item) = Kitties::collection_item(&MALE).unwrap();
+		assert_noop!(
+			<Nfts as Transfer<u64>>::transfer(&collection, &item, &BOB),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+		assert_ok!(Kitties::do_try_state());
+
+		// the migration only runs once
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Kitties::on_chain_storage_version(), 1);
+		assert_eq!(crate::NextItemId::<Test>::get(), 2);
+		assert_eq!(Kitties::trait_count(Gene::BodyColour, 0), 2);
+		assert_ok!(Kitties::do_try_state());
+	});
+}
+
+#[test]
+fn migrate_to_v1_waits_for_a_funded_pallet_account() {
+	use crate::migration::v1;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Kitties>();
+		insert_v0_kitty(ALICE, MALE, Gender::Male, None);
+		insert_v0_kitty(BOB, FEMALE, Gender::Female, None);
+		// the pallet account cannot pay for the kitties collection
+		Balances::make_free_balance_be(&Kitties::account_id(), 0);
+
+		// no kitty is lost, and the storage stays at version 0
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Kitties::on_chain_storage_version(), 0);
+		assert_eq!(v1::Kitties::<Test>::get(MALE).unwrap().owner, ALICE);
+		assert_eq!(v1::KittyOwner::<Test>::get(FEMALE), Some(BOB));
+		assert_eq!(Kitties::all_kitties_count(), 2);
+		assert_eq!(Kitties::kitties_collection(), None);
+
+		// the migration runs again once the pallet account is funded
+		Balances::make_free_balance_be(&Kitties::account_id(), 100);
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Kitties::on_chain_storage_version(), 1);
+		assert_eq!(Kitties::owner_of(&MALE), Some(ALICE));
+		assert_eq!(Kitties::owner_of(&FEMALE), Some(BOB));
+		assert_ok!(Kitties::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_broken_invariants() {
+	new_test_ext().execute_with(|| {
+		let dna = mint(ALICE, MALE);
+		assert_ok!(Kitties::do_try_state());
+
+		// a kitty which is not counted
+		crate::AllKittiesCount::<Test>::put(2);
+		assert!(Kitties::do_try_state().is_err());
+		crate::AllKittiesCount::<Test>::put(1);
+
+		// a kitty without an item
+		let item = Kitties::item_of_kitty(dna).unwrap();
+		crate::ItemOfKitty::<Test>::remove(dna);
+		assert!(Kitties::do_try_state().is_err());
+		crate::ItemOfKitty::<Test>::insert(dna, item);
+		assert_ok!(Kitties::do_try_state());
+	});
+}
+
+#[test]
+fn nonfungible_traits_work() {
+	use codec::Encode;
+	use frame_support::traits::tokens::nonfungible;
+
+	build_and_execute(|| {
+		assert_ok!(<Kitties as nonfungible::Mutate<u64>>::mint_into(&MALE, &ALICE));
+		assert_eq!(<Kitties as nonfungible::Inspect<u64>>::owner(&MALE), Some(ALICE));
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), MALE, Some(10)));
+		assert_eq!(
+			<Kitties as nonfungible::Inspect<u64>>::attribute(&MALE, b"gender"),
+			Some(Gender::Male.encode())
+		);
+		assert_eq!(
+			<Kitties as nonfungible::Inspect<u64>>::typed_attribute::<_, Option<u64>>(
+				&MALE, b"price"
+			),
+			Some(Some(10))
+		);
+		assert_eq!(
+			<Kitties as nonfungible::Inspect<u64>>::typed_attribute::<_, u32>(&MALE, b"generation"),
+			Some(0)
+		);
+
+		// listed kitties can be moved by other pallets, which takes them off sale
+		assert!(<Kitties as nonfungible::Inspect<u64>>::can_transfer(&MALE));
+		assert_ok!(<Kitties as nonfungible::Transfer<u64>>::transfer(&MALE, &BOB));
+		assert_eq!(Kitties::owner_of(&MALE), Some(BOB));
+		assert_eq!(Kitties::kitties(MALE).unwrap().price, None);
+		System::assert_last_event(Event::Transferred { from: ALICE, to: BOB, kitty: MALE }.into());
+	});
+}
+
+#[test]
+fn nonfungible_burn_releases_the_kitty_deposit() {
+	use frame_support::traits::tokens::nonfungible;
+
+	build_and_execute(|| {
+		let bob_reserved = Balances::reserved_balance(BOB);
+		let dna = mint(BOB, MALE);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(BOB), dna, Some(10)));
+		let item = Kitties::item_of_kitty(dna).unwrap();
+
+		assert_noop!(
+			<Kitties as nonfungible::Mutate<u64>>::burn(&dna, Some(&ALICE)),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(<Kitties as nonfungible::Mutate<u64>>::burn(&dna, Some(&BOB)));
+		assert_eq!(Kitties::kitties(dna), None);
+		assert_eq!(Kitties::kitty_of_item(item), None);
+		assert_eq!(Kitties::kitty_deposit(dna), None);
+		assert_eq!(Kitties::all_kitties_count(), 0);
+		// both the item deposit and the kitty deposit are released
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+	});
+}
+
+#[test]
+fn traits_decode_dna() {
+	use crate::traits::{self, Gene, Rarity, TRAITS_VERSION};
+
+	let decoded = traits::decode(&[0; 32]);
+	assert_eq!(decoded.version, TRAITS_VERSION);
+	assert_eq!(decoded.traits.len(), 5);
+	assert_eq!(decoded.traits[0].gene, Gene::BodyColour);
+	assert_eq!(decoded.traits[0].name, b"Cotton".to_vec());
+	assert!(decoded.traits.iter().all(|t| t.value == 0 && t.rarity == Rarity::Common));
+
+	// the highest byte picks the last and rarest value of every gene
+	let decoded = traits::decode(&[255; 32]);
+	assert_eq!(decoded.traits[0].name, b"Cloudwhite".to_vec());
+	assert!(decoded.traits.iter().all(|t| t.rarity == Rarity::Legendary));
+
+	// every value can be picked, and rarer values by fewer bytes
+	for table in traits::table(TRAITS_VERSION).unwrap() {
+		let mut picks = vec![0u32; table.values.len()];
+		for byte in 0..=255u8 {
+			picks[table.pick(byte)] += 1;
+		}
+		for (value, count) in table.values.iter().zip(&picks) {
+			assert!(*count > 0);
+			assert!(value.rarity == Rarity::Common || *count < picks[0]);
+		}
+	}
+
+	// unknown versions cannot be decoded with
+	assert_eq!(traits::decode_with(0, &[0; 32]), None);
+}
+
+#[test]
+fn trait_counts_follow_mints_and_burns() {
+	use crate::traits::Gene;
+	use frame_support::traits::tokens::nonfungible;
+
+	build_and_execute(|| {
+		mint(ALICE, MALE);
+		mint(BOB, FEMALE);
+		let rare = mint(CHARLIE, H256::repeat_byte(255));
+		assert_eq!(Kitties::trait_count(Gene::BodyColour, 0), 2);
+		assert_eq!(Kitties::trait_count(Gene::BodyColour, 14), 1);
+
+		// every trait of MALE is shared by two kitties out of three, and none of the rare one
+		let rarity = Kitties::kitty_rarity(&MALE).unwrap();
+		assert_eq!(rarity.population, 3);
+		assert_eq!(rarity.trait_counts, vec![2; 5]);
+		assert_eq!(rarity.score, 5 * 3 * 100 / 2);
+		let rarity = Kitties::kitty_rarity(&rare).unwrap();
+		assert_eq!(rarity.trait_counts, vec![1; 5]);
+		assert_eq!(rarity.score, 5 * 3 * 100);
+		assert_eq!(Kitties::kitty_rarity(&H256::repeat_byte(3)), None);
+
+		// burned kitties are no longer counted
+		assert_ok!(<Kitties as nonfungible::Mutate<u64>>::burn(&rare, None));
+		assert!(!crate::TraitCounts::<Test>::contains_key(Gene::BodyColour, 14));
+		assert_eq!(Kitties::kitty_rarity(&rare), None);
+		assert_eq!(Kitties::kitty_rarity(&MALE).unwrap().score, 5 * 100);
+	});
+}
+
+#[test]
+fn release_kitty_works() {
+	build_and_execute(|| {
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		let dna = mint(ALICE, MALE);
+		mint(ALICE, FEMALE);
+		mint(ALICE, H256::repeat_byte(4));
+		assert_noop!(
+			Kitties::commit_mint(RuntimeOrigin::signed(ALICE), commitment(ALICE, SECRET)),
+			Error::<Test>::TooManyOwned
+		);
+		assert_ok!(Kitties::set_price(RuntimeOrigin::signed(ALICE), dna, Some(10)));
+
+		assert_noop!(
+			Kitties::release_kitty(RuntimeOrigin::signed(BOB), dna),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Kitties::release_kitty(RuntimeOrigin::signed(ALICE), H256::repeat_byte(9)),
+			Error::<Test>::NoKitty
+		);
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(ALICE), dna));
+		System::assert_last_event(Event::Released { kitty: dna, owner: ALICE }.into());
+		assert_eq!(Kitties::kitties(dna), None);
+		assert_eq!(Kitties::owned_count(&ALICE), 2);
+		assert_eq!(Kitties::all_kitties_count(), 2);
+
+		// the freed slot can be minted into again
+		create_kitty(ALICE);
+
+		// releasing every kitty refunds every deposit
+		for dna in Kitties::kitties_of(&ALICE) {
+			assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(ALICE), dna));
+		}
+		assert_eq!(Kitties::all_kitties_count(), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+	});
+}
+
+#[test]
+fn release_kitty_forgets_kitties_burned_in_nfts() {
+	build_and_execute(|| {
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		let dna = mint(ALICE, MALE);
+		let (collection, item) = Kitties::collection_item(&dna).unwrap();
+		assert_ok!(<Nfts as Mutate<u64, ItemConfig>>::burn(&collection, &item, Some(&ALICE)));
+		assert_eq!(Kitties::owner_of(&dna), None);
+		assert_ok!(Kitties::do_try_state());
+
+		// the kitty left without an item can be released by anyone
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(BOB), dna));
+		System::assert_last_event(Event::Released { kitty: dna, owner: BOB }.into());
+		assert_eq!(Kitties::kitties(dna), None);
+		assert_eq!(Kitties::all_kitties_count(), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_ok!(Kitties::do_try_state());
+	});
+}
+
+#[test]
+fn release_kitty_fails_for_auctioned_kitties() {
+	build_and_execute(|| {
+		let dna = auction(AuctionKind::English, 10, 10);
+		assert_noop!(
+			Kitties::release_kitty(RuntimeOrigin::signed(ALICE), dna),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+// Returns a name or bio for a kitty.
+fn text<S: frame_support::traits::Get<u32>>(text: &[u8]) -> frame_support::BoundedVec<u8, S> {
+	text.to_vec().try_into().unwrap()
+}
+
+fn name(name: &[u8]) -> crate::KittyName<Test> {
+	text(name)
+}
+
+#[test]
+fn set_kitty_name_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		let alice_reserved = Balances::reserved_balance(ALICE);
+
+		// a deposit is reserved for every byte of the name and bio
+		assert_ok!(Kitties::set_kitty_name(
+			RuntimeOrigin::signed(ALICE),
+			dna,
+			text(b"Tom"),
+			Some(text(b"A cat"))
+		));
+		System::assert_last_event(
+			Event::KittyNamed { kitty: dna, owner: ALICE, name: text(b"Tom") }.into(),
+		);
+		assert_eq!(Kitties::kitty_named(name(b"Tom")), Some(dna));
+		let bio = Kitties::kitty_metadata(dna).unwrap().bio.map(|bio| bio.into_inner());
+		assert_eq!(bio, Some(b"A cat".to_vec()));
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved + 8);
+
+		// renaming frees the previous name and adjusts the deposit
+		assert_ok!(Kitties::set_kitty_name(
+			RuntimeOrigin::signed(ALICE),
+			dna,
+			text(b"Tommy"),
+			None
+		));
+		assert_eq!(Kitties::kitty_named(name(b"Tom")), None);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved + 5);
+
+		// the new owner renaming the kitty releases the deposit of the previous one
+		assert_ok!(Kitties::transfer(RuntimeOrigin::signed(ALICE), BOB, dna));
+		assert_ok!(Kitties::set_kitty_name(RuntimeOrigin::signed(BOB), dna, text(b"Tom"), None));
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved - KittyDeposit::get());
+		assert_eq!(Kitties::kitty_metadata(dna).unwrap().depositor, BOB);
+	});
+}
+
+#[test]
+fn set_kitty_name_fails_for_invalid_names() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		let other = mint(BOB, FEMALE);
+		assert_ok!(Kitties::set_kitty_name(RuntimeOrigin::signed(BOB), other, text(b"Tom"), None));
+
+		assert_noop!(
+			Kitties::set_kitty_name(RuntimeOrigin::signed(BOB), dna, text(b"Tim"), None),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Kitties::set_kitty_name(
+				RuntimeOrigin::signed(ALICE),
+				H256::repeat_byte(9),
+				text(b"Tim"),
+				None
+			),
+			Error::<Test>::NoKitty
+		);
+		assert_noop!(
+			Kitties::set_kitty_name(RuntimeOrigin::signed(ALICE), dna, text(b""), None),
+			Error::<Test>::EmptyName
+		);
+		assert_noop!(
+			Kitties::set_kitty_name(RuntimeOrigin::signed(ALICE), dna, text(b"Tom"), None),
+			Error::<Test>::NameTaken
+		);
+		// the deposit has to be covered
+		Balances::make_free_balance_be(&ALICE, 1);
+		assert!(
+			Kitties::set_kitty_name(RuntimeOrigin::signed(ALICE), dna, text(b"Tim"), None).is_err()
+		);
+		assert_eq!(Kitties::kitty_metadata(dna), None);
+	});
+}
+
+#[test]
+fn force_clear_name_works() {
+	build_and_execute(|| {
+		let dna = mint(ALICE, MALE);
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(Kitties::set_kitty_name(RuntimeOrigin::signed(ALICE), dna, text(b"Tom"), None));
+
+		assert_noop!(
+			Kitties::force_clear_name(RuntimeOrigin::signed(ALICE), dna),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Kitties::force_clear_name(RuntimeOrigin::root(), dna));
+		System::assert_last_event(Event::NameCleared { kitty: dna }.into());
+		assert_eq!(Kitties::kitty_metadata(dna), None);
+		assert_eq!(Kitties::kitty_named(name(b"Tom")), None);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_noop!(Kitties::force_clear_name(RuntimeOrigin::root(), dna), Error::<Test>::NoName);
+
+		// the name is free again, and is freed as well when its kitty is released
+		let other = mint(BOB, FEMALE);
+		assert_ok!(Kitties::set_kitty_name(RuntimeOrigin::signed(BOB), other, text(b"Tom"), None));
+		assert_ok!(Kitties::release_kitty(RuntimeOrigin::signed(BOB), other));
+		assert_eq!(Kitties::kitty_named(name(b"Tom")), None);
+	});
+}
@@ -0,0 +1,288 @@
+//! Decoding of the DNA of a kitty into named visual traits.
+//!
+//! Every gene of a kitty is read from one byte of its DNA, which picks one of the values of the
+//! gene. Rarer values are picked by fewer bytes. The decoding tables are versioned: a runtime
+//! upgrade changing what a DNA decodes to adds a new table and bumps `TRAITS_VERSION`, so that
+//! traits decoded with an older table can still be told apart and decoded again. The pallet
+//! counts the kitties having each trait with the current table, so such an upgrade also has to
+//! clear and rebuild the counters from the existing kitties.
+
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	sp_std::vec::Vec,
+	RuntimeDebug,
+};
+use Rarity::*;
+
+/// The version of the decoding table the runtime decodes DNA with.
+pub const TRAITS_VERSION: u16 = 1;
+
+/// A visual feature of a kitty.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gene {
+	BodyColour,
+	EyeShape,
+	AccentColour,
+	Pattern,
+	Mouth,
+}
+
+/// How rare a gene value is.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rarity {
+	Common,
+	Uncommon,
+	Rare,
+	Epic,
+	Legendary,
+}
+
+impl Rarity {
+	/// The weight of a value of this tier in its table. Each tier is picked half as often as
+	/// the one before.
+	pub fn weight(&self) -> u32 {
+		match self {
+			Common => 16,
+			Uncommon => 8,
+			Rare => 4,
+			Epic => 2,
+			Legendary => 1,
+		}
+	}
+}
+
+/// A value of a gene in a decoding table.
+pub struct GeneValue {
+	pub name: &'static str,
+	pub rarity: Rarity,
+}
+
+/// The decoding table of a gene: the byte of the DNA it is read from, and the values the byte
+/// picks from.
+pub struct GeneTable {
+	pub gene: Gene,
+	pub dna_byte: usize,
+	pub values: &'static [GeneValue],
+}
+
+impl GeneTable {
+	/// Returns the index of the value `byte` picks. Every value covers a share of the 256
+	/// possible bytes proportional to the weight of its rarity.
+	pub fn pick(&self, byte: u8) -> usize {
+		let total: u32 = self.values.iter().map(|value| value.rarity.weight()).sum();
+		let mut point = byte as u32 * total / 256;
+		for (index, value) in self.values.iter().enumerate() {
+			if point < value.rarity.weight() {
+				return index
+			}
+			point -= value.rarity.weight();
+		}
+		self.values.len().saturating_sub(1)
+	}
+}
+
+/// A gene of a kitty, decoded from its DNA.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct KittyTrait {
+	pub gene: Gene,
+	/// The index of the value in the table of the gene.
+	pub value: u8,
+	#[cfg_attr(feature = "std", serde(with = "utf8"))]
+	pub name: Vec<u8>,
+	pub rarity: Rarity,
+}
+
+/// The traits a DNA decodes to, along with the version of the table it was decoded with.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct KittyTraits {
+	pub version: u16,
+	pub traits: Vec<KittyTrait>,
+}
+
+/// The points a trait shared by every kitty adds to the rarity score of a kitty.
+pub const RARITY_POINTS: u64 = 100;
+
+/// How rare the traits of a kitty are across the whole population.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct KittyRarity {
+	/// The sum of the points of every trait of the kitty, where a trait shared by one kitty in
+	/// `k` scores `k * RARITY_POINTS`. Rarer kitties score higher.
+	pub score: u64,
+	/// The number of kitties sharing each trait of the kitty, in the order they are decoded.
+	pub trait_counts: Vec<u64>,
+	/// The number of kitties in existence.
+	pub population: u64,
+}
+
+/// Returns the decoding table of the given version, if there is one.
+pub fn table(version: u16) -> Option<&'static [GeneTable]> {
+	match version {
+		1 => Some(&TABLE_V1),
+		_ => None,
+	}
+}
+
+/// Decodes `dna` with the table of the given version, if there is one. Missing DNA bytes are
+/// read as zero.
+pub fn decode_with(version: u16, dna: &[u8]) -> Option<KittyTraits> {
+	let traits = table(version)?
+		.iter()
+		.map(|table| {
+			let index = table.pick(dna.get(table.dna_byte).copied().unwrap_or_default());
+			let value = &table.values[index];
+			KittyTrait {
+				gene: table.gene,
+				value: index as u8,
+				name: value.name.as_bytes().to_vec(),
+				rarity: value.rarity,
+			}
+		})
+		.collect();
+	Some(KittyTraits { version, traits })
+}
+
+/// Decodes `dna` with the table of `TRAITS_VERSION`.
+pub fn decode(dna: &[u8]) -> KittyTraits {
+	decode_with(TRAITS_VERSION, dna).expect("the table of the current version exists; qed")
+}
+
+// Serializes names as strings rather than byte arrays.
+#[cfg(feature = "std")]
+mod utf8 {
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&String::from_utf8_lossy(bytes))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+		String::deserialize(deserializer).map(String::into_bytes)
+	}
+}
+
+const fn value(name: &'static str, rarity: Rarity) -> GeneValue {
+	GeneValue { name, rarity }
+}
+
+// The first table. The genes are read from the same bytes, and have as many values, as the
+// layers of the kitty avatar of the frontend.
+static TABLE_V1: [GeneTable; 5] = [
+	GeneTable {
+		gene: Gene::BodyColour,
+		dna_byte: 0,
+		values: &[
+			value("Cotton", Common),
+			value("Greymist", Common),
+			value("Salmon", Common),
+			value("Chocolate", Common),
+			value("Shadow", Common),
+			value("Orangesoda", Uncommon),
+			value("Cinnamon", Uncommon),
+			value("Aquamarine", Uncommon),
+			value("Sizzurp", Uncommon),
+			value("Mauveover", Rare),
+			value("Coralsunrise", Rare),
+			value("Hintomint", Rare),
+			value("Bananacream", Epic),
+			value("Dragonfruit", Epic),
+			value("Cloudwhite", Legendary),
+		],
+	},
+	GeneTable {
+		gene: Gene::EyeShape,
+		dna_byte: 1,
+		values: &[
+			value("Simple", Common),
+			value("Wonky", Common),
+			value("Googly", Common),
+			value("Serpent", Common),
+			value("Sass", Common),
+			value("Otaku", Uncommon),
+			value("Crazy", Uncommon),
+			value("Wiley", Uncommon),
+			value("Raisedbrow", Uncommon),
+			value("Thicccbrowz", Rare),
+			value("Caffeine", Rare),
+			value("Chronic", Rare),
+			value("Sweetmeloncakes", Epic),
+			value("Dreamer", Epic),
+			value("Alien", Legendary),
+		],
+	},
+	GeneTable {
+		gene: Gene::AccentColour,
+		dna_byte: 2,
+		values: &[
+			value("Belleblue", Common),
+			value("Sandalwood", Common),
+			value("Peach", Common),
+			value("Icy", Common),
+			value("Granitegrey", Common),
+			value("Cashewmilk", Common),
+			value("Kittencream", Uncommon),
+			value("Emeraldgreen", Uncommon),
+			value("Kalahari", Uncommon),
+			value("Shale", Uncommon),
+			value("Purplehaze", Uncommon),
+			value("Hanauma", Uncommon),
+			value("Azaleablush", Rare),
+			value("Missmuffett", Rare),
+			value("Morningglory", Rare),
+			value("Frosting", Rare),
+			value("Daffodil", Epic),
+			value("Flamingo", Epic),
+			value("Buttercup", Epic),
+			value("Bloodred", Legendary),
+		],
+	},
+	GeneTable {
+		gene: Gene::Pattern,
+		dna_byte: 3,
+		values: &[
+			value("Totesbasic", Common),
+			value("Calicool", Common),
+			value("Luckystripe", Common),
+			value("Spock", Common),
+			value("Tigerpunk", Uncommon),
+			value("Rascal", Uncommon),
+			value("Ganado", Uncommon),
+			value("Jaguar", Rare),
+			value("Dippedcone", Epic),
+			value("Thunderstruck", Legendary),
+		],
+	},
+	GeneTable {
+		gene: Gene::Mouth,
+		dna_byte: 4,
+		values: &[
+			value("Whixtensions", Common),
+			value("Pouty", Common),
+			value("Happygokitty", Common),
+			value("Soserious", Common),
+			value("Saycheese", Uncommon),
+			value("Beard", Uncommon),
+			value("Gerbil", Uncommon),
+			value("Tongue", Rare),
+			value("Dali", Epic),
+			value("Moue", Legendary),
+		],
+	},
+];
@@ -1,3 +1,17 @@
+//! Weights for pallet_substratekitties
+//!
+//! These are hand-written placeholder weights, not benchmark results. The reads and writes of
+//! each call are counted from the storage items listed above its function, while the reference
+//! times and proof sizes are estimates. Regenerate this file from the benchmarks in
+//! `benchmarking.rs` on reference hardware, before relying on it on a production chain:
+//!
+//! ```text
+//! cargo build --release --features runtime-benchmarks
+//! ./target/release/node-template benchmark pallet --chain dev \
+//!     --pallet pallet_substratekitties --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/substratekitties/src/weights.rs
+//! ```
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -5,57 +19,629 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_substratekitties.
 pub trait WeightInfo {
-	fn create_kitty() -> Weight;
-	fn transfer() -> Weight;
+	fn transfer(n: u32, ) -> Weight;
 	fn set_price() -> Weight;
 	fn buy_kitty() -> Weight;
+	fn breed_kitty() -> Weight;
+	fn offer_sire() -> Weight;
+	fn breed_with_sire() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn make_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn commit_mint() -> Weight;
+	fn reveal_mint(n: u32, ) -> Weight;
+	fn release_kitty() -> Weight;
+	fn set_kitty_name() -> Weight;
+	fn force_clear_name() -> Weight;
+	fn on_initialize(a: u32, c: u32, ) -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Placeholder weights for pallet_substratekitties, estimated for the recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn create_kitty() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-
-	fn transfer() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn transfer(n: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 12115)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
-
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
 	fn set_price() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(35_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
 	fn buy_kitty() -> Weight {
-		Weight::from_parts(9_000_000, 0)
+		Weight::from_parts(86_000_000, 13992)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: Kitties Kitties (r:2 w:2)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:2 w:0)
+	/// Storage: KittiesNFT Item (r:2 w:0)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn breed_kitty() -> Weight {
+		Weight::from_parts(64_000_000, 262380)
+			.saturating_add(T::DbWeight::get().reads(110_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	fn offer_sire() -> Weight {
+		Weight::from_parts(24_000_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Kitties Kitties (r:2 w:2)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:2 w:0)
+	/// Storage: KittiesNFT Item (r:2 w:0)
+	/// Storage: Kitties SireFees (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	fn breed_with_sire() -> Weight {
+		Weight::from_parts(91_000_000, 267797)
+			.saturating_add(T::DbWeight::get().reads(112_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(39_000_000, 9840)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn bid() -> Weight {
+		Weight::from_parts(97_000_000, 16527)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(108_000_000, 20070)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(34_000_000, 8902)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties Auctions (r:1 w:0)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(92_000_000, 17235)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(25_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn commit_mint() -> Weight {
+		Weight::from_parts(41_000_000, 258630)
+			.saturating_add(T::DbWeight::get().reads(104_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties Kitties (r:5 w:5)
+	/// Storage: Kitties AllKittiesCount (r:1 w:1)
+	/// Storage: Kitties ItemOfKitty (r:5 w:5)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:5 w:5)
+	/// Storage: Kitties NextItemId (r:1 w:1)
+	/// Storage: KittiesNFT Item (r:5 w:5)
+	/// Storage: KittiesNFT Collection (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:5 w:5)
+	/// Storage: KittiesNFT Attribute (r:15 w:15)
+	/// Storage: Kitties TraitCounts (r:25 w:25)
+	/// Storage: Kitties KittyDeposits (r:0 w:5)
+	/// Storage: Kitties KittyOfItem (r:0 w:5)
+	/// Storage: Kitties KittyChildren (r:0 w:2)
+	/// The range of component `n` is `[1, 5]`.
+	fn reveal_mint(n: u32, ) -> Weight {
+		Weight::from_parts(58_000_000, 13287)
+			.saturating_add(Weight::from_parts(183_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7846).saturating_mul(n.into()))
+	}
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:1)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: Kitties Auctions (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Attribute (r:3 w:3)
+	/// Storage: KittiesNFT Collection (r:1 w:1)
+	/// Storage: KittiesNFT ItemMetadataOf (r:1 w:0)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: Kitties AllKittiesCount (r:1 w:1)
+	/// Storage: Kitties TraitCounts (r:5 w:5)
+	/// Storage: KittiesNFT Account (r:0 w:1)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT ItemAttributesApprovalsOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties KittyOfItem (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties NameOf (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn release_kitty() -> Weight {
+		Weight::from_parts(106_000_000, 29480)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties NameOf (r:1 w:2)
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_kitty_name() -> Weight {
+		Weight::from_parts(43_000_000, 14867)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties NameOf (r:0 w:1)
+	fn force_clear_name() -> Weight {
+		Weight::from_parts(25_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: Kitties Auctions (r:50 w:50)
+	/// Storage: Kitties Kitties (r:50 w:50)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:50 w:0)
+	/// Storage: KittiesNFT Item (r:50 w:50)
+	/// Storage: KittiesNFT ItemConfigOf (r:50 w:50)
+	/// Storage: System Account (r:200 w:200)
+	/// Storage: Kitties KittyDeposits (r:50 w:50)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: Kitties MintCommitments (r:50 w:50)
+	/// Storage: KittiesNFT Account (r:0 w:100)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:50)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:50)
+	/// Storage: Kitties SireFees (r:0 w:50)
+	/// Storage: Kitties ForSale (r:0 w:50)
+	/// The range of component `a` is `[0, 50]`.
+	/// The range of component `c` is `[0, 50]`.
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 3087)
+			.saturating_add(Weight::from_parts(105_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((15_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 20070).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn create_kitty() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-
-	fn transfer() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn transfer(n: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 12115)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
-
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
 	fn set_price() -> Weight {
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(35_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
 	fn buy_kitty() -> Weight {
-		Weight::from_parts(9_000_000, 0)
+		Weight::from_parts(86_000_000, 13992)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: Kitties Kitties (r:2 w:2)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:2 w:0)
+	/// Storage: KittiesNFT Item (r:2 w:0)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn breed_kitty() -> Weight {
+		Weight::from_parts(64_000_000, 262380)
+			.saturating_add(RocksDbWeight::get().reads(110_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	fn offer_sire() -> Weight {
+		Weight::from_parts(24_000_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Kitties Kitties (r:2 w:2)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:2 w:0)
+	/// Storage: KittiesNFT Item (r:2 w:0)
+	/// Storage: Kitties SireFees (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	fn breed_with_sire() -> Weight {
+		Weight::from_parts(91_000_000, 267797)
+			.saturating_add(RocksDbWeight::get().reads(112_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(39_000_000, 9840)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn bid() -> Weight {
+		Weight::from_parts(97_000_000, 16527)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: Kitties Auctions (r:1 w:1)
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(108_000_000, 20070)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(34_000_000, 8902)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Kitties Auctions (r:1 w:0)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:0 w:2)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(92_000_000, 17235)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: Kitties Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(25_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:100 w:0)
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn commit_mint() -> Weight {
+		Weight::from_parts(41_000_000, 258630)
+			.saturating_add(RocksDbWeight::get().reads(104_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties MintCommitments (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties Kitties (r:5 w:5)
+	/// Storage: Kitties AllKittiesCount (r:1 w:1)
+	/// Storage: Kitties ItemOfKitty (r:5 w:5)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: KittiesNFT Account (r:5 w:5)
+	/// Storage: Kitties NextItemId (r:1 w:1)
+	/// Storage: KittiesNFT Item (r:5 w:5)
+	/// Storage: KittiesNFT Collection (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:5 w:5)
+	/// Storage: KittiesNFT Attribute (r:15 w:15)
+	/// Storage: Kitties TraitCounts (r:25 w:25)
+	/// Storage: Kitties KittyDeposits (r:0 w:5)
+	/// Storage: Kitties KittyOfItem (r:0 w:5)
+	/// Storage: Kitties KittyChildren (r:0 w:2)
+	/// The range of component `n` is `[1, 5]`.
+	fn reveal_mint(n: u32, ) -> Weight {
+		Weight::from_parts(58_000_000, 13287)
+			.saturating_add(Weight::from_parts(183_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((15_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7846).saturating_mul(n.into()))
+	}
+	/// Storage: Kitties Kitties (r:1 w:1)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:1)
+	/// Storage: KittiesNFT Item (r:1 w:1)
+	/// Storage: Kitties Auctions (r:1 w:0)
+	/// Storage: KittiesNFT ItemConfigOf (r:1 w:1)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Attribute (r:3 w:3)
+	/// Storage: KittiesNFT Collection (r:1 w:1)
+	/// Storage: KittiesNFT ItemMetadataOf (r:1 w:0)
+	/// Storage: Kitties KittyDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: Kitties AllKittiesCount (r:1 w:1)
+	/// Storage: Kitties TraitCounts (r:5 w:5)
+	/// Storage: KittiesNFT Account (r:0 w:1)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:1)
+	/// Storage: KittiesNFT ItemAttributesApprovalsOf (r:0 w:1)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:1)
+	/// Storage: Kitties KittyOfItem (r:0 w:1)
+	/// Storage: Kitties SireFees (r:0 w:1)
+	/// Storage: Kitties NameOf (r:0 w:1)
+	/// Storage: Kitties ForSale (r:0 w:1)
+	fn release_kitty() -> Weight {
+		Weight::from_parts(106_000_000, 29480)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(25_u64))
+	}
+	/// Storage: Kitties Kitties (r:1 w:0)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:1 w:0)
+	/// Storage: KittiesNFT Item (r:1 w:0)
+	/// Storage: Kitties NameOf (r:1 w:2)
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_kitty_name() -> Weight {
+		Weight::from_parts(43_000_000, 14867)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Kitties KittyMetadataOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Kitties NameOf (r:0 w:1)
+	fn force_clear_name() -> Weight {
+		Weight::from_parts(25_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Kitties AuctionsEndingAt (r:1 w:1)
+	/// Storage: Kitties MintCommitmentsExpiringAt (r:1 w:1)
+	/// Storage: Kitties Auctions (r:50 w:50)
+	/// Storage: Kitties Kitties (r:50 w:50)
+	/// Storage: Kitties KittiesCollection (r:1 w:0)
+	/// Storage: Kitties ItemOfKitty (r:50 w:0)
+	/// Storage: KittiesNFT Item (r:50 w:50)
+	/// Storage: KittiesNFT ItemConfigOf (r:50 w:50)
+	/// Storage: System Account (r:200 w:200)
+	/// Storage: Kitties KittyDeposits (r:50 w:50)
+	/// Storage: KittiesNFT CollectionConfigOf (r:1 w:0)
+	/// Storage: KittiesNFT Collection (r:1 w:0)
+	/// Storage: Kitties MintCommitments (r:50 w:50)
+	/// Storage: KittiesNFT Account (r:0 w:100)
+	/// Storage: KittiesNFT ItemPriceOf (r:0 w:50)
+	/// Storage: KittiesNFT PendingSwapOf (r:0 w:50)
+	/// Storage: Kitties SireFees (r:0 w:50)
+	/// Storage: Kitties ForSale (r:0 w:50)
+	/// The range of component `a` is `[0, 50]`.
+	/// The range of component `c` is `[0, 50]`.
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 3087)
+			.saturating_add(Weight::from_parts(105_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((15_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 20070).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
 }
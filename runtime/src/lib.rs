@@ -6,7 +6,15 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use frame_support::traits::AsEnsureOriginWithArg;
+pub mod nfts_api;
+
+use frame_support::{
+	traits::{
+		tokens::nonfungibles_v2::{Inspect, InspectEnumerable},
+		AsEnsureOriginWithArg,
+	},
+	PalletId,
+};
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use pallet_nfts::PalletFeatures;
@@ -16,7 +24,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -48,6 +57,8 @@ use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
+/// Import the kitties pallet, whose types the node uses to build genesis kitties.
+pub use pallet_substratekitties;
 /// Import the template pallet.
 pub use pallet_template;
 
@@ -106,10 +117,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 118,
+	spec_version: 119,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -320,6 +331,21 @@ impl pallet_nfts::Config for Runtime {
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as Verify>::Signer;
 	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+parameter_types! {
+	pub const BaseBreedingCooldown: BlockNumber = MINUTES;
+	pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
+	pub const KittiesProtocolFee: Perbill = Perbill::from_percent(2);
+	pub KittiesTreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+	pub const MaxAuctionDuration: BlockNumber = 7 * DAYS;
+	pub const MintRevealDelay: BlockNumber = 3;
+	pub const MintCommitmentExpiry: BlockNumber = HOURS;
+	pub const MintCommitmentDeposit: Balance = 10 * CENTS;
+	pub const KittyDeposit: Balance = 10 * CENTS;
+	pub const NameDepositPerByte: Balance = CENTS;
 }
 
 impl pallet_substratekitties::Config for Runtime {
@@ -327,7 +353,27 @@ impl pallet_substratekitties::Config for Runtime {
 	type WeightInfo = pallet_substratekitties::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
 	type MaxKittiesOwned = frame_support::pallet_prelude::ConstU32<100>;
-	type KittyRandomness = RandomnessCollectiveFlip;
+	type BaseBreedingCooldown = BaseBreedingCooldown;
+	// the longest cooldown is 2^13 minutes, a little less than 6 days
+	type MaxCooldownIndex = ConstU32<13>;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Nfts = KittiesNFT;
+	type PalletId = KittiesPalletId;
+	type ProtocolFee = KittiesProtocolFee;
+	type TreasuryAccount = KittiesTreasuryAccount;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type MaxAuctionsPerBlock = ConstU32<50>;
+	type MintRevealDelay = MintRevealDelay;
+	type MintCommitmentExpiry = MintCommitmentExpiry;
+	type MaxMintCommitmentsPerBlock = ConstU32<50>;
+	// minting a kitty weighs almost a fifth of a second
+	type MaxMintPerCommit = ConstU32<5>;
+	type MintCommitmentDeposit = MintCommitmentDeposit;
+	type KittyDeposit = KittyDeposit;
+	type MaxNameLen = ConstU32<32>;
+	type MaxBioLen = ConstU32<256>;
+	type NameDepositPerByte = NameDepositPerByte;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -384,6 +430,13 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// A kitty along with its owner, as returned by the kitties runtime API.
+pub type KittyInfo =
+	pallet_substratekitties_runtime_api::KittyInfo<AccountId, Hash, Balance, BlockNumber>;
+/// All migrations of the runtime, aside from the ones declared in the pallets.
+///
+/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
+pub type Migrations = (pallet_substratekitties::migration::v1::MigrateToV1<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -391,6 +444,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -405,6 +459,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_substratekitties, Kitties]
 	);
 }
 
@@ -576,6 +631,92 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_substratekitties_runtime_api::KittiesApi<Block, AccountId, Hash, Balance, BlockNumber>
+		for Runtime
+	{
+		fn kitty(dna: Hash) -> Option<KittyInfo> {
+			Kitties::kitty_with_owner(&dna).map(Into::into)
+		}
+
+		fn kitties_of(account: AccountId) -> Vec<KittyInfo> {
+			Kitties::kitties_of(&account)
+				.iter()
+				.filter_map(Kitties::kitty_with_owner)
+				.map(Into::into)
+				.collect()
+		}
+
+		fn kitties_for_sale(page: u32, page_size: u32) -> Vec<KittyInfo> {
+			let page_size = page_size.min(pallet_substratekitties_runtime_api::MAX_PAGE_SIZE);
+			Kitties::kitties_for_sale(page, page_size)
+				.iter()
+				.filter_map(Kitties::kitty_with_owner)
+				.map(Into::into)
+				.collect()
+		}
+
+		fn total_count() -> u64 {
+			Kitties::all_kitties_count()
+		}
+
+		fn kitty_traits(dna: Hash) -> pallet_substratekitties::traits::KittyTraits {
+			pallet_substratekitties::traits::decode(dna.as_ref())
+		}
+
+		fn kitty_rarity(dna: Hash) -> Option<pallet_substratekitties::traits::KittyRarity> {
+			Kitties::kitty_rarity(&dna)
+		}
+	}
+
+	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
+		fn owner(collection: u32, item: u32) -> Option<AccountId> {
+			<KittiesNFT as Inspect<AccountId>>::owner(&collection, &item)
+		}
+
+		fn collection_owner(collection: u32) -> Option<AccountId> {
+			<KittiesNFT as Inspect<AccountId>>::collection_owner(&collection)
+		}
+
+		fn attribute(collection: u32, item: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			<KittiesNFT as Inspect<AccountId>>::attribute(&collection, &item, &key)
+		}
+
+		fn custom_attribute(
+			account: AccountId,
+			collection: u32,
+			item: u32,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>> {
+			<KittiesNFT as Inspect<AccountId>>::custom_attribute(&account, &collection, &item, &key)
+		}
+
+		fn system_attribute(collection: u32, item: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			<KittiesNFT as Inspect<AccountId>>::system_attribute(&collection, &item, &key)
+		}
+
+		fn collection_attribute(collection: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			<KittiesNFT as Inspect<AccountId>>::collection_attribute(&collection, &key)
+		}
+	}
+
+	impl nfts_api::NftsEnumerableApi<Block, AccountId, u32, u32> for Runtime {
+		fn items_of(account: AccountId, page: u32, page_size: u32) -> Vec<(u32, u32)> {
+			let page_size = page_size.min(nfts_api::MAX_PAGE_SIZE);
+			<KittiesNFT as InspectEnumerable<AccountId>>::owned(&account)
+				.skip(page.saturating_mul(page_size) as usize)
+				.take(page_size as usize)
+				.collect()
+		}
+
+		fn collection_items(collection: u32, page: u32, page_size: u32) -> Vec<u32> {
+			let page_size = page_size.min(nfts_api::MAX_PAGE_SIZE);
+			<KittiesNFT as InspectEnumerable<AccountId>>::items(&collection)
+				.skip(page.saturating_mul(page_size) as usize)
+				.take(page_size as usize)
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
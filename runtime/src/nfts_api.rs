@@ -0,0 +1,25 @@
+//! Runtime API for listing the items of the `KittiesNFT` instance of pallet-nfts.
+//!
+//! `pallet_nfts_runtime_api::NftsApi` only answers point queries, so this complements it with
+//! paginated lookups built on the pallet's `InspectEnumerable` implementation.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+/// The largest page `items_of` and `collection_items` return.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+sp_api::decl_runtime_apis! {
+	pub trait NftsEnumerableApi<AccountId, CollectionId, ItemId>
+	where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+	{
+		/// Returns a page of the items owned by `account`, across all collections.
+		fn items_of(account: AccountId, page: u32, page_size: u32) -> Vec<(CollectionId, ItemId)>;
+
+		/// Returns a page of the items of `collection`.
+		fn collection_items(collection: CollectionId, page: u32, page_size: u32) -> Vec<ItemId>;
+	}
+}